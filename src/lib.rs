@@ -1,31 +1,369 @@
 //! This crate revolves around the single attribute procedural macro invoke_impl, which when applied
 //! to a struct impl block where all the methods or associated functions share identical signatures
-//! will generate functions that help automate the calling process for invoking these functions.
+//! will generate functions that help automate the calling process for invoking these functions. Its
+//! arguments are separated by semicolons, as in #[invoke_impl(name("fast"); clone(0); parallel)],
+//! and are listed under [Arguments](#arguments) below.
 //!
-//! Six functions are generated: invoke_all, invoke_subset, invoke_all_enumerated, invoke_all_enum,
-//! invoke_enumerated, and invoke_enum. When the associated functions or methods of the impl block
-//! have a return type, these functions all take closure parameters that receive this return type.
-//! Additionally, the closures for invoke_all_enumerated and invoke_enumerated take a usize and
-//! the closures for invoke_all_enum and invoke_enum take an enum of the type of the macro generated
-//! enum, which can be used within the closure to determine specifically which function is being
-//! invoked at a given point in time.
+//! # Generated functions
 //!
-//! When there is no return type, invoke_all and invoke_subset do not take a closure, while
-//! invoke_all_enumerated and invoke_enumerated take a closure that takes usize and invoke_all_enum
-//! and invoke_enum take a closure that takes the type of the macro-generated enum.
+//! ## Invoking the functions
 //!
-//! invoke_impl takes two arguments, name (expecting a string literal) and clone (expecting a list
-//! of int literals). Name specifiers an optional name to be appended to the identifiers of
-//! generated code, while clone indicates which 0-indexed parameters of the functions or methods
-//! in the impl block are to be cloned instead of directly forwarded.
+//! These functions are generated for every impl block of synchronous functions:
 //!
-//! Additionally, invoke_impl adds two const fields to the impl block it is on: a list of &str
-//! copies of the identifiers of the invocable functions contained in the impl block, and a usize
-//! of the total count of invocable functions.
+//! - invoke_all invokes every function in the order they are declared in the impl block, and
+//!   invoke_all_rev in reverse order, which suits teardown or cleanup functions that should run in
+//!   the opposite order of their setup counterparts.
+//! - invoke_all_enumerated and invoke_all_enum invoke every function in order too, also passing the
+//!   closure the index of each function or its variant of the generated enum, so the closure can
+//!   tell which function is being invoked at a given point in time.
+//! - invoke_subset, invoke_enumerated and invoke_enum take an Iterator of indices (or of enum
+//!   variants, for invoke_enum) after the closure, and only invoke the functions it designates, in
+//!   the order given.
+//! - invoke_by_name takes the same closure as invoke_enum, followed by an Iterator<Item = &str> of
+//!   names instead of variants, for invoking functions picked by user input, such as command line
+//!   arguments. The names are parsed with the enum's TryFrom<&str> before any function is invoked,
+//!   so an unknown name invokes nothing and is returned as the Err of the Result<(), String> it
+//!   returns.
+//! - invoke_all_then takes an invoke_impl_finish: impl FnMut() after the closure of invoke_all,
+//!   which it calls once after invoking all the functions, e.g. to flush whatever the closure
+//!   buffered. Similarly, invoke_all_progress takes an
+//!   invoke_impl_progress: impl FnMut(usize, usize), which it calls after each function with the
+//!   number of functions completed so far and METHOD_COUNT, so long running invocations can report
+//!   their progress.
+//! - invoke_subset_unique works like invoke_subset, but keeps track of the indices it was given in
+//!   a HashSet and skips those it has seen before, so an index source with duplicates can't invoke
+//!   a function (and its side effects) twice.
+//! - invoke_subset_lazy works like invoke_subset, except that rather than taking the parameters of
+//!   the functions it takes a factory closure FnMut(usize) -> Args, where Args is the parameter or
+//!   tuple of parameters the functions take. The factory is only called for the indices that are
+//!   actually invoked, so expensive arguments are never built for functions that are skipped. It is
+//!   not generated when any parameter's type borrows (a reference or other type with a lifetime).
+//! - invoke_subset_checked takes the same arguments as invoke_subset, but always returns Result<(),
+//!   usize>, stopping at the first index past the last function and returning it as the Err, for
+//!   dispatching on untrusted indices while the other functions keep panicking on them (see
+//!   on_invalid).
 //!
-//! For example:
+//! When the functions have a return type, the closures of all these functions receive it, after the
+//! index or variant if they take one. When there is no return type, invoke_all, invoke_all_rev and
+//! the subset functions take no closure, while the enumerated and enum functions take a closure of
+//! just the index or variant.
+//!
+//! ## Collecting the results
+//!
+//! When there is a return type, these functions are generated as well:
+//!
+//! - invoke_all_tuple takes no closure and returns the results of all the functions as a tuple, in
+//!   the order the functions are declared in the impl block. It is only generated for impl blocks
+//!   of at most twelve functions.
+//! - invoke_all_batch takes a closure FnMut(&[R]) instead, which it calls once with the results of
+//!   all the functions in impl block order, for consumers that process all the results together.
+//! - invoke_all_collect takes no closure and returns the results in impl block order as a Vec,
+//!   which is handy for functions returning trait objects such as Box<dyn Trait>.
+//!   invoke_all_enumerated_collect returns them in a Vec<(usize, R)> paired with the indices of
+//!   their functions, and invoke_all_deque returns them as a VecDeque, for consumers popping them
+//!   off the front as a queue.
+//! - invoke_all_named_map returns the results in a HashMap<&'static str, R> keyed by the names of
+//!   their functions as in METHOD_LIST, which helps telling which function produced which result
+//!   when debugging.
+//! - invoke_all_collect_indices calls all of the functions, for their side effects, but takes an
+//!   invoke_impl_indices: &[usize] after the parameters and returns only the results of the
+//!   functions at those indices, in impl block order.
+//! - invoke_all_results returns all the results wrapped in a generated {struct}Results struct,
+//!   which implements Index for both the generated enum and usize, so a result can be looked up as
+//!   results[Tester1InvokeImpl::fn2] or results[1]; into_vec unwraps the Vec.
+//! - invoke_enum_collect takes no closure and returns the results of the functions designated by an
+//!   IntoIterator over the enum, paired with their variants in a Vec.
+//! - invoke_all_partition<K: Eq + Hash> takes a closure FnMut(&R) -> K classifying each result, and
+//!   returns a HashMap<K, Vec<(usize, R)>> grouping the results, along with the indices of their
+//!   functions, by key.
+//! - invoke_all_extend<E: Extend<R>> takes an invoke_impl_target: &mut E after the parameters and
+//!   extends it with the results in impl block order, so they can be accumulated into an existing
+//!   collection, such as a reused Vec, without allocating a new one.
+//! - invoke_all_into takes an invoke_impl_out: &mut [R; N] after the parameters, where N is
+//!   METHOD_COUNT, and writes the result of each function into the slot at its index, so the caller
+//!   owns the storage and nothing is allocated.
+//! - invoke_all_fold<B> takes an invoke_impl_init: B and a closure FnMut(B, R) -> B after the
+//!   parameters, and folds the results into the accumulator in impl block order, returning the
+//!   final B, rather than collecting them. invoke_all_enum_fold<B> works the same, except that its
+//!   closure FnMut(B, Enum, R) -> B also receives the enum variant of the function each result came
+//!   from, for building reports keyed by function.
+//! - invoke_all_with<A> takes an invoke_impl_acc: &mut A and a closure FnMut(&mut A, R) after the
+//!   parameters, and calls the closure with the accumulator and each result in impl block order, so
+//!   the accumulator is borrowed for the call alone instead of being captured by the closure.
+//! - invoke_all_until works like invoke_all, except that its closure returns a
+//!   core::ops::ControlFlow<()>: once the closure returns ControlFlow::Break(()), the functions
+//!   after the one whose result it was given aren't called. invoke_all_enumerated_until does the
+//!   same with a closure FnMut(usize, R) -> ControlFlow<()>, which is given the index of each
+//!   function along with its result, like that of invoke_all_enumerated.
+//! - invoke_all_iter takes no closure and instead returns an iterator over the results of the
+//!   functions in impl block order, calling each function only once the iterator gets to it;
+//!   skipping ahead with nth or skip never calls the functions skipped over. Each call captures its
+//!   own copy of the arguments, so like the other functions, parameters that aren't Copy need to be
+//!   marked with the clone argument. It isn't generated when the receiver is &mut self, or when the
+//!   functions borrow their parameters or have generic type parameters. The iterator is a struct
+//!   named {struct}InvokeImplIter, followed by the name converted to PascalCase if one is given,
+//!   like the enum.
+//!
+//! When the functions return Result<T, E>, invoke_all_try takes a closure FnMut(T), calls the
+//! functions in order passing each Ok value to the closure, and stops at the first Err, which it
+//! returns; otherwise it returns Ok(()). invoke_all_count_ok takes no closure, calls every function
+//! whether or not the ones before it failed, and returns how many of them returned Ok, for
+//! reporting partial success.
+//!
+//! When the functions return Option<T>, invoke_all_first_some takes no closure, calls the functions
+//! in order until one returns Some, and returns it, without calling the functions after it; if none
+//! of them return Some, it returns None.
+//!
+//! ## Async and unsafe functions
+//!
+//! When the functions are async, the functions above can't call them, so instead only
+//! invoke_all_async is generated. It is itself async, and awaits each function in order before
+//! passing its result to the closure. With the join feature enabled, invoke_all_join is generated
+//! for them as well: it awaits all the functions concurrently with futures::join!, and then passes
+//! their results to the closure in order.
+//!
+//! When the functions are unsafe, the invoke functions (as well as the run_ wrappers and free
+//! functions) are unsafe too, and call them in unsafe blocks, so callers take on the safety
+//! requirements of every function they invoke. invoke_all_iter isn't generated for them, as the
+//! iterator would call them from safe code long after the unsafe call that created it.
+//!
+//! ## Wrappers
+//!
+//! Besides the invoke functions, every invoked function fn1 gets a thin wrapper run_fn1 with the
+//! same signature, which just calls fn1. It handles the parameters like the invoke functions do,
+//! except that with only the one call, parameters marked for cloning (and self taken by value) are
+//! moved instead.
+//!
+//! # Generated items
+//!
+//! ## Constants and name lookups
+//!
+//! invoke_impl adds these items to the impl block it is on, each suffixed with the name if one is
+//! given:
+//!
+//! - METHOD_LIST, an array of the names of the invoked functions, and METHOD_COUNT, their number.
+//!   METHOD_NAMES holds the same names as a &'static [&'static str], whose type is the same for
+//!   every impl block.
+//! - COMPILED_METHOD_NAMES and COMPILED_METHOD_COUNT, which only count the functions compiled under
+//!   the active cfgs (see [Functions gated by cfg](#functions-gated-by-cfg)).
+//! - METHOD_ENUMS, which holds the enum variants in the same order as METHOD_LIST, so the two can
+//!   be zipped into (name, variant) pairs. As it can't hold variants that are compiled out, it
+//!   isn't generated for impl blocks with functions gated by #[cfg].
+//! - selected_names, which takes a u64 bitmask and returns the names of the functions whose bits
+//!   are set (bit i standing for the i-th entry of METHOD_LIST), ignoring bits past the count.
+//! - the const fn is_method_name(&str) -> bool, which checks whether a name is in METHOD_LIST and
+//!   so can validate names in const contexts, e.g. in a const assert!.
+//! - REFERENCE_INDEX, the index of the function marked with #[invoke_reference], if one is. The
+//!   marker singles it out as the canonical implementation the others are checked against, and is
+//!   removed from the output. Marking more than one function, or a skipped one, is an error.
+//!
+//! An impl block without any functions only receives METHOD_COUNT (of zero), METHOD_LIST,
+//! METHOD_NAMES, their COMPILED_ counterparts, selected_names and is_method_name, as there is
+//! nothing to invoke and no variants for an enum.
+//!
+//! ## The enum
+//!
+//! The generated enum has a variant for every invoked function. It is named {struct}InvokeImpl,
+//! followed by the name converted to PascalCase if one is given (e.g. Tester1InvokeImplMyName for
+//! name("MY_NAME")), unless enum_name names it. Each variant carries the doc comments of its
+//! function, so they show up in rustdoc and IDE hovers on the variant too. Variants are named after
+//! their functions, unless rename names them; the &str conversions follow the variant names, while
+//! default_variant keeps naming the function.
+//!
+//! The enum derives Debug, Clone, Copy, PartialEq, Eq and Hash. It converts into its usize index
+//! and into the &str of its variant name, and implements TryFrom<usize> returning an Err on indices
+//! out of range (or From<usize> panicking on them instead, with panic_conversions), as well as
+//! TryFrom<&str> and FromStr with the same &'static str error, so
+//! "fn1".parse::<Tester1InvokeImpl>() works too. The same conversions are available as the inherent
+//! const fn index(self) -> usize and fn from_index(usize) -> Option<Self>, and the variants
+//! themselves as the const array VARIANTS, which iter() iterates over. The consts COUNT and NAMES
+//! mirror METHOD_COUNT and METHOD_LIST on the enum, for code that only has the enum at hand; like
+//! them, they include functions compiled out by #[cfg], which VARIANTS leaves out.
+//!
+//! The enum also maps its variants to and from the names they are serialized as, with the inherent
+//! const fn serialized_name(self) -> &'static str and fn from_serialized_name(&str) ->
+//! Option<Self>. A variant is serialized as the name of its function, unless the function is marked
+//! #[invoke_serde_name("v1_fn1")], which lets the function be renamed without breaking names that
+//! were persisted. The marker is removed from the output, and two functions serialized as the same
+//! name are an error.
+//!
+//! ## Free functions, invokers and modules
+//!
+//! For impl blocks of associated functions, free_fns additionally generates a module of free
+//! functions, named after the struct in snake_case followed by _invoke (and the name, if one is
+//! given), e.g. tester1_invoke. It holds a function for each invoke function, named without the
+//! invoke_ prefix, which takes the same parameters and calls it: tester1_invoke::all(...) calls
+//! Tester1::invoke_all(...), and tester1_invoke::r#enum calls Tester1::invoke_enum.
+//!
+//! For impl blocks of methods taking &self, invoker("Tester1Invoker") generates a struct of that
+//! name holding a &Tester1, created by Tester1Invoker::new(&tester1), with a method for each invoke
+//! function, named like the free functions of free_fns, that calls it on the held reference:
+//! Tester1Invoker::new(&tester1).all(...) calls tester1.invoke_all(...), and .subset(...) calls
+//! tester1.invoke_subset(...). The struct takes the generics of the impl block.
+//!
+//! Free functions grouped in a module can be invoked with the sibling attribute invoke_mod, which
+//! takes the same arguments: #[invoke_mod] mod shapes { ... } gathers the functions of shapes in an
+//! impl block on a hidden type named after the module in PascalCase (Shapes), expands it like
+//! invoke_impl would, and adds a free function to the module for every invoke function and a const
+//! for every const, so shapes::invoke_all(...) invokes every function of shapes, and
+//! shapes::METHOD_COUNT counts them. The enum is named after the hidden type, as in
+//! ShapesInvokeImpl. Another item of the module named like the hidden type is an error.
+//!
+//! ## Visibility and attributes
+//!
+//! The generated enum, structs and consts are pub, and the invoke functions take the visibility of
+//! the functions they invoke, unless vis sets the visibility of all of them. Private functions
+//! can't be wrapped by pub items, so vis("pub"), or free_fns or invoker without a narrower vis, on
+//! an inherent impl of private functions is an error. The invoke functions don't take the other
+//! attributes of the functions, such as their doc comments or #[inline], which describe the
+//! functions rather than the invoke functions; each invoke function gets a generated doc comment
+//! describing what it does instead, along with #[allow(clippy::too_many_arguments)], as the
+//! parameters it adds to those of the functions can push it past that lint's limit in crates
+//! denying it.
+//!
+//! On a trait impl, such as impl Shape for Square, the functions of the trait are invoked, but as a
+//! trait impl can't hold anything besides the items of its trait, the generated functions and
+//! consts go into a separate inherent impl block with the same generics, impl Square. Trait
+//! functions have no visibility of their own, so the invoke functions are pub too, unless vis says
+//! otherwise. The calls go through the trait, so it has to be in scope, as it usually is where it
+//! is implemented.
+//!
+//! ## Functions gated by cfg
+//!
+//! Functions gated by #[cfg(...)] attributes take their enum variant, conversion arms and calls in
+//! the invoke functions with them, so the generated code compiles under every combination of cfgs.
+//! Indices stay the positions of the functions as written in the impl block whichever cfgs are
+//! active: a compiled out function leaves a gap rather than shifting the indices after it, so index
+//! i always refers to the same function. METHOD_COUNT and METHOD_LIST still count every function as
+//! written, as index i of METHOD_LIST names function i, while COMPILED_METHOD_COUNT and
+//! COMPILED_METHOD_NAMES only count the functions compiled under the active cfgs, in impl block
+//! order. As the shape of their results would depend on the cfgs, invoke_all_tuple,
+//! invoke_all_iter, invoke_all_fastest and invoke_all_join aren't generated for impl blocks with
+//! gated functions.
+//!
+//! ## Parameters and recursion
+//!
+//! Parameters destructured by a pattern, such as (x, y): (i32, i32), &n: &u32 or _: bool, are bound
+//! to arg{i} in the invoke function signatures instead, i being the position of the parameter as
+//! counted by clone, and forwarded from there.
+//!
+//! An invoked function referring to one of the invoke functions, as in Self::invoke_all(...), calls
+//! itself through it, recursing without end unless the call is guarded. Each such reference gets a
+//! warning, raised as the use of a deprecated item, which #[allow(deprecated)] on the function
+//! silences where the recursion is intended.
+//!
+//! # Arguments
+//!
+//! Naming:
+//!
+//! - name("my_name") appends _my_name to the identifiers of the generated functions and consts, so
+//!   several invoke_impl blocks can share a type.
+//! - prefix("foo") prepends foo_ to the names of the invoke functions, as in foo_invoke_all, and
+//!   verb("dispatch") replaces their invoke, as in dispatch_all; both can be combined with name.
+//!   Generated names colliding with items of the impl block are an error.
+//! - enum_name("Tester1Function") names the generated enum directly.
+//! - rename(fn1 = "First") names the variant of fn1, which is handy for functions named by raw
+//!   identifiers or verbose names. A rename target that isn't a valid identifier, or that another
+//!   variant already has, is an error.
+//!
+//! Selecting functions:
+//!
+//! - skip("helper", ...) names functions of the impl block that are left out of everything
+//!   generated, i.e. the invoke functions, the enum and the consts, so helpers can live in the same
+//!   impl block. Naming a function that isn't in the impl block is an error.
+//! - include("forward", ...) names the only functions of the impl block that are invoked. skip and
+//!   include can't be given together.
+//!
+//! Passing arguments and results:
+//!
+//! - clone(0, 2) indicates which 0-indexed parameters of the functions are to be cloned instead of
+//!   directly forwarded (invoke_all moves them into its last call, which nothing follows). The
+//!   indices don't count the receiver of methods, so 0 is the first parameter after self in methods
+//!   just like the first parameter of associated functions, and an index past the last parameter is
+//!   an error.
+//! - clone_self allows methods taking self by value, such as consuming builder methods, by passing
+//!   each call a clone of self, so the type has to be Clone. Invoke functions calling every method
+//!   once in order, such as invoke_all, move self into the last call instead of cloning it once
+//!   more. invoke_all_iter, invoke_all_join, invoke_all_par and invoke_all_par_each aren't
+//!   available for such methods, and without clone_self they are an error.
+//! - consumer_mode(all = "ref", all_enum = "owned") picks, per invoke function named without its
+//!   invoke_ prefix, whether its closure receives results by value (the default) or by reference;
+//!   here invoke_all takes a FnMut(&R) closure.
+//! - args_struct(Tester1Args) generates invoke_all_from, which takes args: &Tester1Args in place of
+//!   the parameters and calls invoke_all with its fields, each field named like the parameter it is
+//!   passed as. Parameters taking a reference borrow their field, while all others get a clone of
+//!   it. It is an error for functions that are async or generic, or whose parameters are patterns
+//!   or mutable references.
+//!
+//! Shaping the enum:
+//!
+//! - panic_conversions makes the enum's conversion from usize infallible, mapping indices to
+//!   variants in impl block order and panicking (like out of bounds array indexing) on indices out
+//!   of range.
+//! - default_variant(fn1) implements Default for the enum, returning the variant of the named
+//!   function.
+//! - repr(u16), or any of u8, u16, u32 or u64, declares the enum #[repr(u16)] with each variant's
+//!   index in the impl block as its discriminant, and adds the const fn as_u16(self) -> u16
+//!   returning it. Having more functions than the integer can index is an error.
+//!
+//! Generating more:
+//!
+//! - on_invalid(skip), on_invalid(panic) or on_invalid(error) picks what invoke_subset,
+//!   invoke_subset_unique, invoke_subset_lazy and invoke_enumerated do with indices past the last
+//!   function: skip moves on to the next index, panic keeps the default, and error makes them
+//!   return Result<(), usize>, stopping at the first invalid index and returning it as the Err.
+//! - vis("pub(crate)") sets the visibility of all the generated items, which keeps them from
+//!   leaking out of a library when the impl block sits in a private module.
+//! - free_fns generates a module of free functions, and invoker("Tester1Invoker") an invoker
+//!   struct, as described above.
+//! - parallel generates invoke_all_par and invoke_all_par_each, with the rayon feature (see below).
+//!
+//! # Features
+//!
+//! - std, which is enabled by default, generates the items that need std: invoke_all_collect,
+//!   invoke_all_enumerated_collect, invoke_all_deque, invoke_all_collect_indices,
+//!   invoke_all_named_map, invoke_all_partition, invoke_enum_collect, invoke_all_results,
+//!   invoke_all_iter, invoke_by_name, invoke_subset_unique and selected_names. With default
+//!   features disabled, they are left out and the rest of the generated code only uses core, so
+//!   invoke_impl works in #![no_std] crates. The timing, catch_unwind and rayon features enable
+//!   std.
+//! - join generates invoke_all_join for async functions, as described above. Code using it needs to
+//!   depend on the futures crate.
+//! - timing generates invoke_all_fastest, which takes no closure, times each function and returns a
+//!   tuple of the enum variant of the fastest function, how long it took, and its result.
+//! - catch_unwind generates invoke_first_non_panicking, which takes no closure, calls the functions
+//!   in order, catching panics with std::panic::catch_unwind, and returns the result of the first
+//!   function that doesn't panic, or None if they all do. The calls are asserted to be unwind safe,
+//!   so state a panicking function leaves half updated (behind shared references or in the
+//!   parameters) is seen by the functions after it; for this reason it isn't generated when the
+//!   receiver is &mut self. Panics are still reported by the panic hook.
+//! - rayon allows the parallel argument. invoke_all_par takes no closure, runs all the functions in
+//!   parallel on a rayon scope, and returns their results as a Vec in the order the functions are
+//!   declared in the impl block. invoke_all_par_each runs them the same way, but passes each result
+//!   to a consumer as soon as its function returns, in whatever order they finish; since the
+//!   consumer is called from the threads running the functions, it must be FnMut(R) + Send, and a
+//!   consumer that isn't Send is reported at the call passing it. Since every function gets its own
+//!   copies of the arguments, every parameter must be marked with the clone argument; the functions
+//!   must also have a return type, can't take &mut self and can't have generic type parameters, or
+//!   else parallel is an error. The arguments, results and the type of self must also be Send or
+//!   Sync as rayon requires. Code using it needs to depend on the rayon crate.
+//! - log precedes every generated call of a function with a log::debug!("invoking {}", name) naming
+//!   that function, so running the invoke functions leaves a trace in the logs. Code using it needs
+//!   to depend on the log crate.
+//! - serde implements serde's Serialize and Deserialize for the generated enum, (de)serializing
+//!   each variant as its serialized name, so selections of functions can be read from configuration
+//!   files. Deserializing any other name is an error. Code using it needs to depend on the serde
+//!   crate.
+//! - dump writes the pretty-printed expansion of every annotated impl block to a file named after
+//!   its enum, in the directory named by the INVOKE_IMPL_DUMP_DIR environment variable, if that
+//!   variable is set at compile time.
+//!
+//! # Example
+//!
+//! The impl block
 //!
 //!```
+//!    # use invoke_impl::invoke_impl;
 //!    struct Tester1;
 //!
 //!    #[invoke_impl]
@@ -43,9 +381,10 @@
 //!         }
 //!     }
 //!```
-//! is expanded into the following code:
+//! is expanded into code like the following, of which only the first few invoke functions and
+//! enum impls are shown:
 //!
-//! ```
+//! ```ignore
 //!     struct Tester1;
 //!     impl Tester1 {
 //!       pub fn fn1(i: i32) -> i32 {
@@ -58,9 +397,9 @@
 //!           i
 //!       }
 //!       pub fn invoke_all(i: i32, mut consumer: impl FnMut(i32)) {
-//!           consumer(Tester1::fn1(i));
-//!           consumer(Tester1::fn2(i));
-//!           consumer(Tester1::fn3(i));
+//!           consumer(Self::fn1(i));
+//!           consumer(Self::fn2(i));
+//!           consumer(Self::fn3(i));
 //!       }
 //!       pub fn invoke_subset(
 //!           i: i32,
@@ -69,9 +408,9 @@
 //!       ) {
 //!           for invoke_impl_i in invoke_impl_iter {
 //!               match invoke_impl_i {
-//!                   0usize => consumer(Tester1::fn1(i)),
-//!                   1usize => consumer(Tester1::fn2(i)),
-//!                   2usize => consumer(Tester1::fn3(i)),
+//!                   0usize => consumer(Self::fn1(i)),
+//!                   1usize => consumer(Self::fn2(i)),
+//!                   2usize => consumer(Self::fn3(i)),
 //!                   _ => ::core::panicking::panic_fmt(::core::fmt::Arguments::new_v1(
 //!                       &["Iter contains invalid function index!"],
 //!                       &[],
@@ -80,14 +419,14 @@
 //!           }
 //!       }
 //!       pub fn invoke_all_enumerated(i: i32, mut consumer: impl FnMut(usize, i32)) {
-//!           consumer(0usize, Tester1::fn1(i));
-//!           consumer(1usize, Tester1::fn2(i));
-//!           consumer(2usize, Tester1::fn3(i));
+//!           consumer(0usize, Self::fn1(i));
+//!           consumer(1usize, Self::fn2(i));
+//!           consumer(2usize, Self::fn3(i));
 //!       }
-//!       pub fn invoke_all_enum(i: i32, mut consumer: impl FnMut(Tester1_invoke_impl_enum, i32)) {
-//!           consumer(Tester1_invoke_impl_enum::fn1, Tester1::fn1(i));
-//!           consumer(Tester1_invoke_impl_enum::fn2, Tester1::fn2(i));
-//!           consumer(Tester1_invoke_impl_enum::fn3, Tester1::fn3(i));
+//!       pub fn invoke_all_enum(i: i32, mut consumer: impl FnMut(Tester1InvokeImpl, i32)) {
+//!           consumer(Tester1InvokeImpl::fn1, Self::fn1(i));
+//!           consumer(Tester1InvokeImpl::fn2, Self::fn2(i));
+//!           consumer(Tester1InvokeImpl::fn3, Self::fn3(i));
 //!       }
 //!       pub fn invoke_enumerated(
 //!           i: i32,
@@ -97,13 +436,13 @@
 //!           for invoke_impl_i in invoke_impl_iter {
 //!               match invoke_impl_i {
 //!                   0usize => {
-//!                       consumer(0usize, Tester1::fn1(i));
+//!                       consumer(0usize, Self::fn1(i));
 //!                   }
 //!                   1usize => {
-//!                       consumer(1usize, Tester1::fn2(i));
+//!                       consumer(1usize, Self::fn2(i));
 //!                   }
 //!                   2usize => {
-//!                       consumer(2usize, Tester1::fn3(i));
+//!                       consumer(2usize, Self::fn3(i));
 //!                   }
 //!                   _ => ::core::panicking::panic_fmt(::core::fmt::Arguments::new_v1(
 //!                       &["Iter contains invalid function index!"],
@@ -114,19 +453,19 @@
 //!       }
 //!       pub fn invoke_enum(
 //!           i: i32,
-//!           mut consumer: impl FnMut(Tester1_invoke_impl_enum, i32),
-//!           mut invoke_impl_iter: impl Iterator<Item = Tester1_invoke_impl_enum>,
+//!           mut consumer: impl FnMut(Tester1InvokeImpl, i32),
+//!           mut invoke_impl_iter: impl Iterator<Item = Tester1InvokeImpl>,
 //!       ) {
 //!           for invoke_impl_i in invoke_impl_iter {
 //!               match invoke_impl_i {
-//!                   Tester1_invoke_impl_enum::fn1 => {
-//!                       consumer(Tester1_invoke_impl_enum::fn1, Tester1::fn1(i));
+//!                   Tester1InvokeImpl::fn1 => {
+//!                       consumer(Tester1InvokeImpl::fn1, Self::fn1(i));
 //!                   }
-//!                   Tester1_invoke_impl_enum::fn2 => {
-//!                       consumer(Tester1_invoke_impl_enum::fn2, Tester1::fn2(i));
+//!                   Tester1InvokeImpl::fn2 => {
+//!                       consumer(Tester1InvokeImpl::fn2, Self::fn2(i));
 //!                   }
-//!                   Tester1_invoke_impl_enum::fn3 => {
-//!                       consumer(Tester1_invoke_impl_enum::fn3, Tester1::fn3(i));
+//!                   Tester1InvokeImpl::fn3 => {
+//!                       consumer(Tester1InvokeImpl::fn3, Self::fn3(i));
 //!                   }
 //!               }
 //!           }
@@ -134,27 +473,27 @@
 //!       pub const METHOD_COUNT: usize = 3usize;
 //!       pub const METHOD_LIST: [&'static str; 3usize] = ["fn1", "fn2", "fn3"];
 //!   }
-//!   pub enum Tester1_invoke_impl_enum {
+//!   pub enum Tester1InvokeImpl {
 //!       fn1,
 //!       fn2,
 //!       fn3,
 //!   }
 //!   #[automatically_derived]
 //!   #[allow(unused_qualifications)]
-//!   impl ::core::fmt::Debug for Tester1_invoke_impl_enum {
+//!   impl ::core::fmt::Debug for Tester1InvokeImpl {
 //!       fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
 //!           match (&*self,) {
-//!               (&Tester1_invoke_impl_enum::fn1,) => ::core::fmt::Formatter::write_str(f, "fn1"),
-//!               (&Tester1_invoke_impl_enum::fn2,) => ::core::fmt::Formatter::write_str(f, "fn2"),
-//!               (&Tester1_invoke_impl_enum::fn3,) => ::core::fmt::Formatter::write_str(f, "fn3"),
+//!               (&Tester1InvokeImpl::fn1,) => ::core::fmt::Formatter::write_str(f, "fn1"),
+//!               (&Tester1InvokeImpl::fn2,) => ::core::fmt::Formatter::write_str(f, "fn2"),
+//!               (&Tester1InvokeImpl::fn3,) => ::core::fmt::Formatter::write_str(f, "fn3"),
 //!           }
 //!       }
 //!   }
 //!   #[automatically_derived]
 //!   #[allow(unused_qualifications)]
-//!   impl ::core::clone::Clone for Tester1_invoke_impl_enum {
+//!   impl ::core::clone::Clone for Tester1InvokeImpl {
 //!       #[inline]
-//!       fn clone(&self) -> Tester1_invoke_impl_enum {
+//!       fn clone(&self) -> Tester1InvokeImpl {
 //!           {
 //!               *self
 //!           }
@@ -162,15 +501,16 @@
 //!   }
 //!   #[automatically_derived]
 //!   #[allow(unused_qualifications)]
-//!   impl ::core::marker::Copy for Tester1_invoke_impl_enum {}
-//!   impl Tester1_invoke_impl_enum {
-//!       pub fn iter() -> impl Iterator<Item = &'static Tester1_invoke_impl_enum> {
-//!           use Tester1_invoke_impl_enum::*;
-//!           static members: [Tester1_invoke_impl_enum; 3usize] = [fn1, fn2, fn3];
-//!           members.iter()
+//!   impl ::core::marker::Copy for Tester1InvokeImpl {}
+//!   // ... as well as the derived PartialEq, Eq and Hash impls
+//!   impl Tester1InvokeImpl {
+//!       pub const VARIANTS: [Tester1InvokeImpl; 3usize] = [Self::fn1, Self::fn2, Self::fn3];
+//!       pub fn iter() -> impl Iterator<Item = &'static Tester1InvokeImpl> {
+//!           static MEMBERS: [Tester1InvokeImpl; 3usize] = Tester1InvokeImpl::VARIANTS;
+//!           MEMBERS.iter()
 //!       }
 //!   }
-//!   impl TryFrom<&str> for Tester1_invoke_impl_enum {
+//!   impl TryFrom<&str> for Tester1InvokeImpl {
 //!       type Error = &'static str;
 //!       fn try_from(value: &str) -> Result<Self, Self::Error> {
 //!           match value {
@@ -181,9 +521,9 @@
 //!           }
 //!       }
 //!   }
-//!   impl From<Tester1_invoke_impl_enum> for &str {
-//!       fn from(en: Tester1_invoke_impl_enum) -> Self {
-//!           use Tester1_invoke_impl_enum::*;
+//!   impl From<Tester1InvokeImpl> for &str {
+//!       fn from(en: Tester1InvokeImpl) -> Self {
+//!           use Tester1InvokeImpl::*;
 //!           match en {
 //!               fn1 => "fn1",
 //!               fn2 => "fn2",
@@ -191,180 +531,1016 @@
 //!           }
 //!       }
 //!   }
-///```
+//!```
 
 use proc_macro::TokenStream;
-use quote::{format_ident, quote, ToTokens};
-use syn::FnArg::Typed;
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::__private::Span;
 use syn::__private::{str, Default};
 use syn::parse::Parser;
 use syn::punctuated::Punctuated;
+use syn::visit::{self, Visit};
+use syn::visit_mut::{self, VisitMut};
+use syn::FnArg::Typed;
 use syn::{
-    parse_macro_input, Block, Expr, ExprCall, ExprForLoop, ExprMatch, FnArg, GenericParam, Ident,
-    ImplItem, ImplItemMethod, ItemEnum, ItemImpl, Lit, MetaList, NestedMeta, Pat, ReturnType,
-    Signature, Stmt, Type,
+    parse_macro_input, Arm, Attribute, Block, Expr, ExprCall, ExprForLoop, ExprMatch,
+    ExprMethodCall, FnArg, GenericArgument, GenericParam, Ident, ImplItem, ImplItemMethod, Item,
+    ItemEnum, ItemFn, ItemImpl, ItemMod, Lifetime, LifetimeDef, Lit, LitInt, LitStr, Meta,
+    MetaList, NestedMeta, Pat, PatType, PathArguments, Receiver, ReturnType, Signature, Stmt, Type,
+    TypeReference, Visibility,
 };
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-/// Proc macro which appends six different functions to a struct impl block that each represent
-/// different ways of invoking functions or methods implemented in that impl block, as well as
-/// two associated constants.
+/// Proc macro which appends several different functions to a struct impl block that each represent
+/// different ways of invoking functions or methods implemented in that impl block, along with
+/// constants naming and counting them and an enum of them. The crate documentation lists everything
+/// generated and the arguments it takes.
 #[proc_macro_attribute]
 pub fn invoke_impl(args: TokenStream, item: TokenStream) -> TokenStream {
-    let mut input = parse_macro_input!(item as ItemImpl);
-    let (name, clones) = parse_args(args);
+    let input = parse_macro_input!(item as ItemImpl);
+    expand_impl(args, input)
+}
+
+/// Proc macro which does for the free functions of a module what invoke_impl does for the
+/// functions of an impl block, appending free invoke functions and constants to the module.
+#[proc_macro_attribute]
+pub fn invoke_mod(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut module = parse_macro_input!(item as ItemMod);
+
+    // The functions of the module are gathered in an impl block on a type named after the module,
+    // which goes through invoke_impl like any other
+    let type_ident = format_ident!("{}", to_pascal_case(&module.ident.to_string()));
+    let error = match module.content {
+        None => Some(syn::Error::new_spanned(
+            &module,
+            "invoke_mod needs a module with a body, as in mod name { ... }",
+        )),
+        Some((_, ref content)) => content
+            .iter()
+            .find(|item| item_ident(item) == Some(&type_ident))
+            .map(|item| {
+                syn::Error::new_spanned(
+                    item,
+                    format!(
+                        "invoke_mod generates a type named `{}` in the module, which is taken by \
+                         this item",
+                        type_ident
+                    ),
+                )
+            }),
+    };
+    if let Some(e) = error {
+        let mut unchanged = module.to_token_stream();
+        unchanged.extend(e.to_compile_error());
+        return unchanged.into();
+    }
+    let content = &mut module.content.as_mut().unwrap().1;
+
+    let mut wrappers = vec![];
+    let mut fn_idents = vec![];
+    for item in content.iter_mut() {
+        if let Item::Fn(function) = item {
+            wrappers.push(create_mod_wrapper(function));
+            fn_idents.push(function.sig.ident.clone());
+        }
+    }
+    let gathered: ItemImpl = syn::parse(quote!(impl #type_ident { #(#wrappers)* }).into()).unwrap();
+    let expansion = expand_impl(args, gathered);
+
+    // Everything generated goes into the module, and every generated function and constant of the
+    // impl block, other than the wrappers, gets a free counterpart delegating to it
+    let run_idents = fn_idents
+        .iter()
+        .map(|ident| format_ident!("run_{}", ident))
+        .collect::<Vec<_>>();
+    let expansion: syn::File = syn::parse(expansion).unwrap();
+    content.push(
+        syn::parse(
+            quote!(
+                /// Type the functions of the module are gathered on for invoke_mod; it has no
+                /// values, as it only namespaces them
+                #[doc(hidden)]
+                enum #type_ident {}
+            )
+            .into(),
+        )
+        .unwrap(),
+    );
+    for item in expansion.items {
+        if let Item::Impl(ref gathered) = item {
+            if gathered.trait_.is_none()
+                && get_struct_identifier_as_path(gathered) == Ok(type_ident.clone())
+            {
+                for impl_item in &gathered.items {
+                    match impl_item {
+                        ImplItem::Method(method)
+                            if !fn_idents.contains(&method.sig.ident)
+                                && !run_idents.contains(&method.sig.ident) =>
+                        {
+                            let ident = &method.sig.ident;
+                            let target = syn::parse(quote!(#type_ident::#ident).into()).unwrap();
+                            content.push(Item::Fn(create_delegating_fn(
+                                &method.vis,
+                                &method.sig,
+                                &target,
+                                None,
+                            )));
+                        }
+                        ImplItem::Const(constant) => {
+                            let (vis, ident, ty) = (&constant.vis, &constant.ident, &constant.ty);
+                            content.push(
+                                syn::parse(
+                                    quote!(#vis const #ident: #ty = #type_ident::#ident;).into(),
+                                )
+                                .unwrap(),
+                            );
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        content.push(item);
+    }
+
+    module.into_token_stream().into()
+}
+
+/// Helper function to report an error at the impl block, which is returned unchanged alongside it
+/// so that the error is the only one reported.
+fn with_error(input: &ItemImpl, e: syn::Error) -> TokenStream {
+    let mut unchanged = input.to_token_stream();
+    unchanged.extend(e.to_compile_error());
+    unchanged.into()
+}
+
+/// Expands an impl block annotated with invoke_impl (given its args), appending the generated
+/// functions and constants to it and the generated types after it.
+fn expand_impl(args: TokenStream, mut input: ItemImpl) -> TokenStream {
+    let InvokeImplArgs {
+        name,
+        clones: clone_lits,
+        consumer_modes,
+        panic_conversions,
+        skip,
+        include,
+        default_variant,
+        parallel,
+        prefix,
+        verb,
+        enum_name,
+        clone_self,
+        vis,
+        free_fns,
+        on_invalid,
+        renames,
+        args_struct,
+        repr,
+        invoker,
+    } = match parse_args(args) {
+        Ok(args) => args,
+        Err(e) => return with_error(&input, e),
+    };
 
-    // Get a vec of references to ImplItemMethods in the impl block
+    // Take the #[invoke_reference] marker off the functions, noting which one carried it
+    let reference = match take_reference_marker(&mut input) {
+        Ok(reference) => reference,
+        Err(e) => return with_error(&input, e),
+    };
+
+    // Likewise take the #[invoke_serde_name] overrides off the functions
+    let serde_names = match take_serde_names(&mut input) {
+        Ok(serde_names) => serde_names,
+        Err(e) => return with_error(&input, e),
+    };
+
+    // Check the functions selected by skip or include, if any
+    if let Err(e) = validate_selection(&input, &skip, &include) {
+        return with_error(&input, e);
+    }
+
+    // Get a vec of references to ImplItemMethods in the impl block, leaving out skipped ones or
+    // keeping only included ones
     let methods = input
         .items
         .iter()
         .filter_map(|item| match item {
-            ImplItem::Method(method) => Some(method),
+            ImplItem::Method(method) if is_selected(method, &skip, &include) => Some(method),
             _ => None,
         })
         .collect::<Vec<_>>();
 
+    // The default variant has to be one of the variants of the enum
+    if let Some(ref variant) = default_variant {
+        if !methods.iter().any(|method| method.sig.ident == *variant) {
+            return with_error(
+                &input,
+                syn::Error::new_spanned(
+                    variant,
+                    format!(
+                        "default_variant `{}` isn't one of the functions invoked by invoke_impl",
+                        variant
+                    ),
+                ),
+            );
+        }
+    }
+
+    // The reference function has to be one of the functions invoked too
+    let reference_index = match reference {
+        Some(reference) => match methods
+            .iter()
+            .position(|method| method.sig.ident == reference)
+        {
+            Some(index) => Some(index),
+            None => {
+                return with_error(
+                    &input,
+                    syn::Error::new_spanned(
+                        &reference,
+                        format!(
+                            "#[invoke_reference] marks `{}`, which isn't one of the functions \
+                             invoked by invoke_impl",
+                            reference
+                        ),
+                    ),
+                );
+            }
+        },
+        None => None,
+    };
+
+    // Every function is serialized as its name, unless #[invoke_serde_name] overrides it
+    let serialized_names = match get_serialized_names(&methods, &serde_names) {
+        Ok(serialized_names) => serialized_names,
+        Err(e) => return with_error(&input, e),
+    };
+
+    // Every function's enum variant is named after it, unless rename says otherwise
+    let variant_idents = match get_variant_idents(&methods, &renames) {
+        Ok(variant_idents) => variant_idents,
+        Err(e) => return with_error(&input, e),
+    };
+
     // Get the number of available functions in the impl block
     let count = methods.len();
 
+    // The repr has to hold the index of every function, as it is the discriminant of its variant
+    if let Some(ref repr) = repr {
+        if count > 0 && (count - 1) as u64 > repr_max(repr).unwrap() {
+            return with_error(
+                &input,
+                syn::Error::new_spanned(
+                    repr,
+                    format!(
+                        "`{}` can't hold the indices of all {} functions",
+                        repr, count
+                    ),
+                ),
+            );
+        }
+    }
+
+    // Items that don't stem from a function are public unless vis says otherwise
+    let item_vis = vis
+        .clone()
+        .unwrap_or_else(|| syn::parse(quote!(pub).into()).unwrap());
+
     // Get a list of identifiers for available functions:
     let names = methods
         .iter()
         .map(|iim| iim.sig.ident.to_string())
         .collect::<Vec<_>>();
 
-    // Validate all methods share identical structure
-    validate_signatures(methods[0], &methods);
+    // Generate the number of functions (excluding those added by macro):
+    let mc_ident = if let Some(ref s) = name {
+        format_ident!("METHOD_COUNT_{}", s)
+    } else {
+        format_ident!("METHOD_COUNT")
+    };
+    let method_count: ImplItem =
+        syn::parse(quote!(#item_vis const #mc_ident: usize = #count;).into()).unwrap();
 
-    let struct_ident = get_struct_identifier_as_path(&input).unwrap();
+    // Generate an array containing all function identifiers
+    let ml_ident = if let Some(ref s) = name {
+        format_ident!("METHOD_LIST_{}", s)
+    } else {
+        format_ident!("METHOD_LIST")
+    };
+    let method_list: ImplItem = syn::parse(
+        quote!(#item_vis const #ml_ident: [&'static str; #count] = [#(#names),*];).into(),
+    )
+    .unwrap();
 
-    // Generate enum
-    let enum_tokenstream = create_enum(&methods, &struct_ident, &name);
+    // Generate a slice borrowing that array, whose type doesn't depend on the number of functions
+    let mn_ident = if let Some(ref s) = name {
+        format_ident!("METHOD_NAMES_{}", s)
+    } else {
+        format_ident!("METHOD_NAMES")
+    };
+    let method_names: ImplItem = syn::parse(
+        quote!(#item_vis const #mn_ident: &'static [&'static str] = &Self::#ml_ident;).into(),
+    )
+    .unwrap();
 
-    // Generate invoke_all function to impl block:
-    let invoke_all = create_invoke_function(
-        methods[0],
-        &methods,
-        &struct_ident,
-        InvokeType::All,
-        &name,
-        &clones,
-    );
+    // Generate the names and number of the functions compiled under the active cfgs, which the
+    // list and count above include whether or not they are compiled
+    let cmn_ident = if let Some(ref s) = name {
+        format_ident!("COMPILED_METHOD_NAMES_{}", s)
+    } else {
+        format_ident!("COMPILED_METHOD_NAMES")
+    };
+    let compiled_names = methods.iter().zip(&names).map(|(&method, name)| {
+        let cfgs = cfg_attrs(method);
+        quote!(#(#cfgs)* #name)
+    });
+    let compiled_method_names: ImplItem = syn::parse(
+        quote!(#item_vis const #cmn_ident: &'static [&'static str] = &[#(#compiled_names),*];)
+            .into(),
+    )
+    .unwrap();
+    let cmc_ident = if let Some(ref s) = name {
+        format_ident!("COMPILED_METHOD_COUNT_{}", s)
+    } else {
+        format_ident!("COMPILED_METHOD_COUNT")
+    };
+    let compiled_method_count: ImplItem =
+        syn::parse(quote!(#item_vis const #cmc_ident: usize = Self::#cmn_ident.len();).into())
+            .unwrap();
 
-    // Generate invoke_subset function to impl block:
-    let invoke_subset = create_invoke_function(
-        methods[0],
-        &methods,
-        &struct_ident,
-        InvokeType::Subset,
-        &name,
-        &clones,
-    );
+    // Generate a function naming the functions selected by a bitmask, bit i selecting function i
+    let sn_ident = if let Some(ref s) = name {
+        format_ident!("selected_names_{}", s)
+    } else {
+        format_ident!("selected_names")
+    };
+    // It returns a Vec, so it needs the std feature
+    let selected_names: Option<ImplItem> = if cfg!(feature = "std") {
+        Some(
+            syn::parse(
+                quote!(
+                    #item_vis fn #sn_ident(mask: u64) -> ::std::vec::Vec<&'static str> {
+                        Self::#ml_ident
+                            .iter()
+                            .enumerate()
+                            .filter(|(i, _)| *i < 64 && (mask >> i) & 1 == 1)
+                            .map(|(_, name)| *name)
+                            .collect()
+                    }
+                )
+                .into(),
+            )
+            .unwrap(),
+        )
+    } else {
+        None
+    };
 
-    // Generate invoke_all_enumerated function to impl block:
-    let invoke_all_enumerated = create_invoke_function(
-        methods[0],
-        &methods,
-        &struct_ident,
-        InvokeType::SpecifiedAll(SpecificationType::Enumerated),
-        &name,
-        &clones,
-    );
+    // Generate a const fn checking whether a name is in the list; str equality isn't const, so the
+    // bytes are compared by hand
+    let imn_ident = if let Some(ref s) = name {
+        format_ident!("is_method_name_{}", s)
+    } else {
+        format_ident!("is_method_name")
+    };
+    let is_method_name: ImplItem = syn::parse(
+        quote!(
+            #item_vis const fn #imn_ident(name: &str) -> bool {
+                let name = name.as_bytes();
+                let mut i = 0;
+                while i < Self::#ml_ident.len() {
+                    let candidate = Self::#ml_ident[i].as_bytes();
+                    if candidate.len() == name.len() {
+                        let mut j = 0;
+                        while j < name.len() && candidate[j] == name[j] {
+                            j += 1;
+                        }
+                        if j == name.len() {
+                            return true;
+                        }
+                    }
+                    i += 1;
+                }
+                false
+            }
+        )
+        .into(),
+    )
+    .unwrap();
 
-    // Generate invoke_all_enum function to impl block:
-    let invoke_all_enum = create_invoke_function(
-        methods[0],
-        &methods,
-        &struct_ident,
-        InvokeType::SpecifiedAll(SpecificationType::Enum),
-        &name,
-        &clones,
-    );
+    // An impl block without functions has nothing to invoke (and an enum without variants would
+    // be uninhabited), so only the consts are appended
+    if methods.is_empty() {
+        let mut generated = vec![
+            method_count,
+            method_list,
+            method_names,
+            compiled_method_count,
+            compiled_method_names,
+        ];
+        generated.extend(selected_names);
+        generated.push(is_method_name);
+        if let Err(e) = validate_generated_names(&input, &generated) {
+            return with_error(&input, e);
+        }
+        input.items.extend(generated);
+        return input.into_token_stream().into();
+    }
 
-    // Generate invoke_enumerated function to impl block:
-    let invoke_enumerated = create_invoke_function(
-        methods[0],
+    // Check no invoked function has the name of an invoke function, before its signature (likely
+    // unlike the others) gets reported instead
+    if let Err(e) = validate_method_names(
         &methods,
-        &struct_ident,
-        InvokeType::Specified(SpecificationType::Enumerated),
         &name,
-        &clones,
-    );
+        &prefix,
+        &verb,
+        parallel,
+        args_struct.is_some(),
+    ) {
+        return with_error(&input, e);
+    }
+
+    // Validate methods and associated functions aren't mixed, which is reported on its own as no
+    // change to the signatures alone resolves it
+    if let Err(e) = validate_receivers(methods[0], &methods) {
+        return with_error(&input, e);
+    }
+
+    // Validate all methods share identical structure
+    if let Err(e) = validate_signatures(methods[0], &methods) {
+        return with_error(&input, e);
+    }
+
+    // Validate the results can be collected, which needs them to be sized
+    if let Err(e) = validate_sized_return(methods[0]) {
+        return with_error(&input, e);
+    }
+
+    // Validate the clone indices name parameters of the functions, before they are looked up
+    if let Err(e) = validate_clone_indices(methods[0], &clone_lits) {
+        return with_error(&input, e);
+    }
+    let clones = clone_lits.map(|lits| {
+        lits.iter()
+            .map(|lit| lit.base10_parse::<usize>().unwrap())
+            .collect::<HashSet<_>>()
+    });
+
+    // Validate self is cloned exactly when it is taken by value
+    if let Err(e) = validate_clone_self(methods[0], clone_self) {
+        return with_error(&input, e);
+    }
+
+    // Validate there is no self to pass on, if free functions are asked for
+    if let Err(e) = validate_free_fns(methods[0], free_fns) {
+        return with_error(&input, e);
+    }
+
+    // Validate there is a shared self to hold, if an invoker is asked for
+    if let Err(e) = validate_invoker(methods[0], &invoker) {
+        return with_error(&input, e);
+    }
+
+    // Validate no pub item wrapping the functions exposes private ones
+    if let Err(e) = validate_exported_surface(&input, methods[0], &vis, free_fns, &invoker) {
+        return with_error(&input, e);
+    }
+
+    // Validate the parameters can be taken from the fields of a struct, if asked to
+    if args_struct.is_some() {
+        if let Err(e) = validate_args_struct(methods[0]) {
+            return with_error(&input, e);
+        }
+    }
 
-    // Generate invoke_enum function to impl block:
-    let invoke_enum = create_invoke_function(
-        methods[0],
+    // Validate the methods can run in parallel, if asked to
+    if parallel {
+        if let Err(e) = validate_parallel(methods[0], &clones) {
+            return with_error(&input, e);
+        }
+    }
+
+    let struct_ident = get_struct_identifier_as_path(&input).unwrap();
+    let enum_ident = generate_enum_name(&struct_ident, &name, &enum_name);
+
+    // Generate enum
+    let enum_tokenstream = create_enum(
         &methods,
+        &variant_idents,
         &struct_ident,
-        InvokeType::Specified(SpecificationType::Enum),
-        &name,
-        &clones,
+        &enum_ident,
+        panic_conversions,
+        &default_variant,
+        &serialized_names,
+        &repr,
+        &item_vis,
     );
 
-    input.items.push(invoke_all);
-    input.items.push(invoke_subset);
-    input.items.push(invoke_all_enumerated);
-    input.items.push(invoke_all_enum);
-    input.items.push(invoke_enumerated);
-    input.items.push(invoke_enum);
+    // Select which invoke functions to generate for the impl block:
+    let base_sig = &methods[0].sig;
+    let gated = methods.iter().any(|method| !cfg_attrs(method).is_empty());
+    let mut invoke_types = vec![];
+    if base_sig.asyncness.is_some() {
+        // Async functions need to be awaited, so only the async invoke functions can call them
+        invoke_types.push(InvokeType::AllAsync);
 
-    // Append the number of functions (excluding those added by macro) to the impl block:
-    let mc_ident = if let Some(ref s) = name {
-        format_ident!("METHOD_COUNT_{}", s)
+        // invoke_all_join, if joining is enabled and futures::join! takes every function (on self
+        // by reference, as self can't be cloned inside the macro):
+        if cfg!(feature = "join") && !gated && !has_value_receiver(base_sig) {
+            invoke_types.push(InvokeType::AllJoin);
+        }
     } else {
-        format_ident!("METHOD_COUNT")
-    };
-    input
-        .items
-        .push(syn::parse(quote!(pub const #mc_ident: usize = #count;).into()).unwrap());
+        invoke_types.extend([
+            InvokeType::All,
+            InvokeType::AllRev,
+            InvokeType::AllThen,
+            InvokeType::Progress,
+            InvokeType::Subset,
+            InvokeType::UniqueSubset,
+            InvokeType::CheckedSubset,
+            InvokeType::SpecifiedAll(SpecificationType::Enumerated),
+            InvokeType::SpecifiedAll(SpecificationType::Enum),
+            InvokeType::Specified(SpecificationType::Enumerated),
+            InvokeType::Specified(SpecificationType::Enum),
+            InvokeType::ByName,
+        ]);
 
-    // Append an array containing all function identifiers into the tokenstream
-    let ml_ident = if let Some(ref s) = name {
-        format_ident!("METHOD_LIST_{}", s)
-    } else {
-        format_ident!("METHOD_LIST")
-    };
-    input.items.push(
-        syn::parse(quote!(pub const #ml_ident: [&'static str; #count] = [#(#names),*];).into())
-            .unwrap(),
-    );
+        // invoke_all_tuple, if the results fit in a tuple whose type doesn't depend on cfgs:
+        if count <= MAX_TUPLE_ARITY && has_return_type(&base_sig.output) && !gated {
+            invoke_types.push(InvokeType::Tuple);
+        }
 
-    let mut revised_impl: TokenStream = input.into_token_stream().into();
-    revised_impl.extend(enum_tokenstream);
-    revised_impl
-}
+        // The invoke functions passing on, collecting, folding or keying the results, if there are
+        // results:
+        if has_return_type(&base_sig.output) {
+            invoke_types.extend([
+                InvokeType::Batch,
+                InvokeType::Collect,
+                InvokeType::EnumeratedCollect,
+                InvokeType::CollectIndices,
+                InvokeType::Deque,
+                InvokeType::NamedMap,
+                InvokeType::Partition,
+                InvokeType::Extend,
+                InvokeType::Into,
+                InvokeType::Fold,
+                InvokeType::EnumFold,
+                InvokeType::With,
+                InvokeType::EnumCollect,
+            ]);
+        }
 
-/// Helper enum to specify which kind of specification an invoke function uses: enumerated (usize)
-/// or enum (the associated enum constructed).
-#[derive(Copy, Clone)]
-enum SpecificationType {
-    Enum,
-    Enumerated,
-}
+        // invoke_all_results, if there are results whose positions don't depend on cfgs:
+        if has_return_type(&base_sig.output) && !gated {
+            invoke_types.push(InvokeType::Results);
+        }
 
-/// Helper enum to indicate which type of invoke function is being built
-#[derive(Copy, Clone)]
-enum InvokeType {
-    /// invoke function takes in specified intoiter over either usize or enum with matching closure
-    /// and only invokes functions designed by intoiter
-    Specified(SpecificationType),
-    /// invoke function has closure taking in either usize or enum plus returntype, invoked over
-    /// all functions in marked impl block
-    SpecifiedAll(SpecificationType),
-    /// invoke function has closure only taking returntype, invoked over intoiter of usize to
-    /// indicate which functions get called
-    Subset,
-    /// invoke function has a closure only taking returntype, invoked over all functions in impl
-    /// block
-    All,
-}
+        // invoke_subset_lazy, if the arguments can be built lazily:
+        if !params_mention_lifetimes(base_sig) {
+            invoke_types.push(InvokeType::LazySubset);
+        }
 
-/// Creates a function that generates an invoke in the impl block (all methods to be invoked must
-/// share the same signature, excepting details like names, comments, etc).
-/// Note that rather than returning, the
-/// invoke  functions will share the same parameter signature as the impl block functions but
+        // invoke_all_iter, if the calls can be deferred until the iterator gets to them (which
+        // unsafe functions can't be, as the iterator would call them from safe code):
+        if has_return_type(&base_sig.output)
+            && base_sig.unsafety.is_none()
+            && !params_mention_lifetimes(base_sig)
+            && !has_mut_receiver(base_sig)
+            && !has_type_generics(base_sig)
+            && !has_value_receiver(base_sig)
+            && !gated
+        {
+            invoke_types.push(InvokeType::Iter);
+        }
+
+        // invoke_all_until and invoke_all_enumerated_until, if there are results for the closure
+        // to decide on:
+        if has_return_type(&base_sig.output) {
+            invoke_types.extend([InvokeType::Until, InvokeType::EnumeratedUntil]);
+        }
+
+        // invoke_all_try and invoke_all_count_ok, if the functions return Result:
+        if split_result_type(&base_sig.output).is_some() {
+            invoke_types.extend([InvokeType::Try, InvokeType::CountOk]);
+        }
+
+        // invoke_all_first_some, if the functions return Option:
+        if split_option_type(&base_sig.output).is_some() {
+            invoke_types.push(InvokeType::FirstSome);
+        }
+
+        // invoke_all_fastest, if timing is enabled and the first function is always there:
+        if cfg!(feature = "timing") && !gated {
+            invoke_types.push(InvokeType::Fastest);
+        }
+
+        // invoke_first_non_panicking, if catching panics is enabled and a panic can't leave self
+        // half updated:
+        if cfg!(feature = "catch_unwind") && !has_mut_receiver(base_sig) {
+            invoke_types.push(InvokeType::FirstNonPanicking);
+        }
+
+        // invoke_all_par and invoke_all_par_each, if asked for:
+        if parallel {
+            invoke_types.extend([InvokeType::Par, InvokeType::ParEach]);
+        }
+    }
+
+    // Without the std feature, only the invoke functions that get by with core are generated, so
+    // they work in no_std crates
+    if !cfg!(feature = "std") {
+        invoke_types.retain(|&invoke_type| !requires_std(invoke_type));
+    }
+
+    // invoke_all_iter returns an iterator over a generated struct:
+    let iter_tokenstream = if invoke_types
+        .iter()
+        .any(|invoke_type| matches!(invoke_type, InvokeType::Iter))
+    {
+        Some(create_iter_struct(&struct_ident, &name, &item_vis))
+    } else {
+        None
+    };
+
+    // invoke_all_results returns a generated struct indexable by the enum:
+    let results_tokenstream = if invoke_types
+        .iter()
+        .any(|invoke_type| matches!(invoke_type, InvokeType::Results))
+    {
+        Some(create_results_struct(
+            &struct_ident,
+            &name,
+            &enum_ident,
+            &item_vis,
+        ))
+    } else {
+        None
+    };
+
+    // Generate the selected invoke functions:
+    let invoke_functions = invoke_types
+        .iter()
+        .map(|&invoke_type| {
+            create_invoke_function(
+                methods[0],
+                &methods,
+                &struct_ident,
+                invoke_type,
+                &name,
+                &prefix,
+                &verb,
+                &enum_ident,
+                &variant_idents,
+                &clones,
+                &consumer_modes,
+                on_invalid.unwrap_or(InvalidIndexMode::Panic),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // Generate the index of the function marked #[invoke_reference], if any
+    let reference_index = reference_index.map(|index| -> ImplItem {
+        let ri_ident = if let Some(ref s) = name {
+            format_ident!("REFERENCE_INDEX_{}", s)
+        } else {
+            format_ident!("REFERENCE_INDEX")
+        };
+        syn::parse(quote!(#item_vis const #ri_ident: usize = #index;).into()).unwrap()
+    });
+
+    // Generate a wrapper calling each function on its own:
+    let mut run_functions = methods
+        .iter()
+        .map(|&method| create_run_function(method))
+        .collect::<Vec<_>>();
+
+    // Generate an array of the variants in the order of METHOD_LIST, if every one of them exists
+    let method_enums = if gated {
+        None
+    } else {
+        let me_ident = if let Some(ref s) = name {
+            format_ident!("METHOD_ENUMS_{}", s)
+        } else {
+            format_ident!("METHOD_ENUMS")
+        };
+        Some(
+            syn::parse::<ImplItem>(
+                quote!(#item_vis const #me_ident: [#enum_ident; #count] =
+                    [#(#enum_ident::#variant_idents),*];)
+                .into(),
+            )
+            .unwrap(),
+        )
+    };
+
+    // The invoke functions and wrappers take the visibility of the functions they call, unless vis
+    // overrides it. Functions of trait impls have no visibility of their own, so for those they
+    // take that of the other generated items.
+    let mut invoke_functions = invoke_functions;
+    let fn_vis = if input.trait_.is_some() {
+        Some(item_vis.clone())
+    } else {
+        vis.clone()
+    };
+    if let Some(ref vis) = fn_vis {
+        for item in invoke_functions.iter_mut().chain(run_functions.iter_mut()) {
+            if let ImplItem::Method(method) = item {
+                method.vis = vis.clone();
+            }
+        }
+    }
+
+    // Generate invoke_all_from, taking the arguments of invoke_all from a struct, if asked for
+    let invoke_all_from = args_struct.as_ref().map(|args_struct| {
+        let position = invoke_types
+            .iter()
+            .position(|invoke_type| matches!(invoke_type, InvokeType::All))
+            .unwrap();
+        create_invoke_all_from_function(
+            &invoke_functions[position],
+            methods[0],
+            args_struct,
+            &name,
+            &prefix,
+            &verb,
+        )
+    });
+
+    // Warn about invoked functions calling the invoke functions, which call them right back
+    let invoke_names = invoke_functions
+        .iter()
+        .chain(invoke_all_from.iter())
+        .filter_map(impl_item_name)
+        .cloned()
+        .collect::<Vec<_>>();
+    let recursion_warnings = create_recursion_warnings(&methods, &invoke_names);
+
+    // Generate a module of free functions delegating to the invoke functions, if asked for
+    let free_fns_tokenstream = if free_fns {
+        Some(create_free_fns_module(
+            &invoke_functions,
+            &invoke_types,
+            &struct_ident,
+            &name,
+            &item_vis,
+        ))
+    } else {
+        None
+    };
+
+    // Generate a struct dispatching to the invoke functions through its methods, if asked for
+    let invoker_tokenstream = invoker.as_ref().map(|invoker| {
+        create_invoker_struct(
+            &invoke_functions,
+            &invoke_types,
+            &input,
+            &struct_ident,
+            invoker,
+            &item_vis,
+        )
+    });
+
+    // Append the invoke functions, the wrappers, the number of functions and the array of their
+    // identifiers to the impl block, as long as their names are all free:
+    let mut generated = invoke_functions;
+    generated.extend(invoke_all_from);
+    generated.extend(run_functions);
+    generated.extend([
+        method_count,
+        method_list,
+        method_names,
+        compiled_method_count,
+        compiled_method_names,
+    ]);
+    generated.extend(selected_names);
+    generated.push(is_method_name);
+    generated.extend(reference_index);
+    generated.extend(method_enums);
+    if let Err(e) = validate_generated_names(&input, &generated) {
+        return with_error(&input, e);
+    }
+
+    // A trait impl can only hold the items of its trait, so the generated items of trait impls go
+    // into an inherent impl block of their own, with the same generics
+    let inherent_impl = if input.trait_.is_some() {
+        Some(ItemImpl {
+            attrs: vec![],
+            defaultness: None,
+            unsafety: None,
+            trait_: None,
+            items: generated,
+            ..input.clone()
+        })
+    } else {
+        input.items.extend(generated);
+        None
+    };
+    for item in input.items.iter_mut() {
+        if let ImplItem::Method(method) = item {
+            for (_, warning) in recursion_warnings
+                .iter()
+                .filter(|(ident, _)| *ident == method.sig.ident)
+            {
+                method.block.stmts.insert(0, warning.clone());
+            }
+        }
+    }
+
+    let mut revised_impl: TokenStream = input.into_token_stream().into();
+    if let Some(inherent_impl) = inherent_impl {
+        revised_impl.extend(TokenStream::from(inherent_impl.into_token_stream()));
+    }
+    revised_impl.extend(enum_tokenstream);
+    revised_impl.extend(iter_tokenstream);
+    revised_impl.extend(results_tokenstream);
+    revised_impl.extend(free_fns_tokenstream);
+    revised_impl.extend(invoker_tokenstream);
+
+    #[cfg(feature = "dump")]
+    dump_expansion(&enum_ident, &revised_impl);
+
+    revised_impl
+}
+
+/// Name of the environment variable holding the directory expansions are written to when the dump
+/// feature is enabled.
+#[cfg(feature = "dump")]
+const DUMP_DIR_VAR: &str = "INVOKE_IMPL_DUMP_DIR";
+
+/// Writes the pretty-printed expansion of an annotated impl block to a file in the directory named
+/// by INVOKE_IMPL_DUMP_DIR, if that variable is set. The file is named after the generated enum,
+/// which is unique per impl block, so several annotated impl blocks don't overwrite each other.
+#[cfg(feature = "dump")]
+fn dump_expansion(enum_name: &Ident, expansion: &TokenStream) {
+    let dir = match std::env::var_os(DUMP_DIR_VAR) {
+        Some(dir) => std::path::PathBuf::from(dir),
+        None => return,
+    };
+    let file: syn::File = syn::parse(expansion.clone()).unwrap();
+    let path = dir.join(format!("{}.rs", enum_name));
+    if let Err(e) = std::fs::create_dir_all(&dir)
+        .and_then(|_| std::fs::write(&path, prettyplease::unparse(&file)))
+    {
+        panic!(
+            "Failed to dump invoke_impl expansion to {}: {}",
+            path.display(),
+            e
+        );
+    }
+}
+
+/// Helper enum to specify which kind of specification an invoke function uses: enumerated (usize)
+/// or enum (the associated enum constructed).
+#[derive(Copy, Clone)]
+enum SpecificationType {
+    Enum,
+    Enumerated,
+}
+
+/// Helper enum to indicate which type of invoke function is being built
+#[derive(Copy, Clone)]
+enum InvokeType {
+    /// invoke function takes in specified intoiter over either usize or enum with matching closure
+    /// and only invokes functions designed by intoiter
+    Specified(SpecificationType),
+    /// invoke function has closure taking in either usize or enum plus returntype, invoked over
+    /// all functions in marked impl block
+    SpecifiedAll(SpecificationType),
+    /// invoke function works like Specified(Enum), but takes the names of the functions to invoke,
+    /// returning the first one that doesn't name any as an error
+    ByName,
+    /// invoke function has closure only taking returntype, invoked over intoiter of usize to
+    /// indicate which functions get called
+    Subset,
+    /// invoke function works like Subset, but skips indices whose functions it already invoked
+    UniqueSubset,
+    /// invoke function works like Subset, but returns the first index out of range as an error
+    /// regardless of on_invalid
+    CheckedSubset,
+    /// invoke function has a closure only taking returntype, invoked over all functions in impl
+    /// block
+    All,
+    /// invoke function has a closure only taking returntype, invoked over all functions in impl
+    /// block in reverse order
+    AllRev,
+    /// invoke function has a closure only taking returntype, invoked over all functions in impl
+    /// block, and a closure called once after all of them
+    AllThen,
+    /// invoke function has a closure only taking returntype, invoked over all functions in impl
+    /// block, and a closure called with the number of functions completed so far and the total
+    /// after each of them
+    Progress,
+    /// invoke function has no closure and returns the results of all functions in impl block as a
+    /// tuple
+    Tuple,
+    /// invoke function has a closure taking a slice of returntype, which it calls once with the
+    /// results of all functions in impl block
+    Batch,
+    /// invoke function has no closure and returns the results of all functions in impl block as a
+    /// Vec
+    Collect,
+    /// invoke function has no closure and returns the results of all functions in impl block as a
+    /// Vec, each paired with the index of its function
+    EnumeratedCollect,
+    /// invoke function has no closure and returns the results of all functions in impl block as a
+    /// VecDeque
+    Deque,
+    /// invoke function has no closure and returns the results of all functions in impl block in a
+    /// HashMap keyed by their names
+    NamedMap,
+    /// invoke function has no closure, invokes all functions in impl block and returns the results
+    /// of those at the given indices as a Vec
+    CollectIndices,
+    /// invoke function has no closure and extends a target collection with the results of all
+    /// functions in impl block
+    Extend,
+    /// invoke function has no closure and writes the results of all functions in impl block into
+    /// the slots of an array matching their indices
+    Into,
+    /// invoke function has a closure folding returntype into an accumulator, which it returns after
+    /// folding in the results of all functions in impl block
+    Fold,
+    /// invoke function works like Fold, but also passes the enum variant of each function to the
+    /// closure
+    EnumFold,
+    /// invoke function has a closure taking a mutable reference to an accumulator, which it takes
+    /// as a parameter, and returntype, invoked over all functions in impl block
+    With,
+    /// invoke function has no closure and returns the results of all functions in impl block in a
+    /// generated struct indexable by both the enum and usize
+    Results,
+    /// invoke function has no closure, invokes the functions designated by an intoiter over the
+    /// enum and returns their results paired with their variants as a Vec
+    EnumCollect,
+    /// invoke function has a closure classifying returntype by a key, and returns the results of
+    /// all functions in impl block (with their indices) grouped by key in a HashMap
+    Partition,
+    /// invoke function works like Subset, but instead of taking the parameters of the functions it
+    /// takes a factory closure building them only for the functions that are actually invoked
+    LazySubset,
+    /// invoke function has no closure, times every function in impl block and returns the enum,
+    /// duration and result of the fastest one
+    Fastest,
+    /// invoke function has no closure, invokes the functions in impl block in order until one
+    /// doesn't panic, and returns its result as an Option
+    FirstNonPanicking,
+    /// invoke function has no closure, invokes the functions returning Option in impl block in
+    /// order until one returns Some, and returns it
+    FirstSome,
+    /// invoke function has a closure taking returntype and returning ControlFlow, invoked over all
+    /// functions in impl block until it breaks
+    Until,
+    /// invoke function works like Until, but its closure also takes the index of each function
+    EnumeratedUntil,
+    /// invoke function has a closure taking the Ok type of functions returning Result, invoked
+    /// over all functions in impl block until one returns Err, which is returned
+    Try,
+    /// invoke function has no closure, invokes all functions in impl block and returns how many
+    /// of them returned Ok
+    CountOk,
+    /// invoke function is async, has a closure only taking returntype, and awaits all async
+    /// functions in impl block in order
+    AllAsync,
+    /// invoke function is async, has a closure only taking returntype, and awaits all async
+    /// functions in impl block concurrently before passing their results on in order
+    AllJoin,
+    /// invoke function has no closure, runs all functions in impl block in parallel and returns
+    /// their results as a Vec in impl block order
+    Par,
+    /// invoke function has a Send closure only taking returntype, which it calls with the result
+    /// of each function in impl block as soon as it completes, running them all in parallel
+    ParEach,
+    /// invoke function has no closure and returns an iterator that calls each function in impl
+    /// block only once it gets to it, yielding its result
+    Iter,
+}
+
+/// Helper enum to specify how an invoke function passes results to its consumer closure: by value
+/// (the default) or by reference, for results that aren't Clone or are expensive to move.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum ConsumerMode {
+    Owned,
+    Ref,
+}
+
+/// Helper enum to specify what the invoke functions dispatching on usize indices do with an index
+/// out of range: skip it, panic (the default) or return it as an Err.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum InvalidIndexMode {
+    Skip,
+    Panic,
+    Error,
+}
+
+/// Largest number of functions in an impl block for which invoke_all_tuple is generated, matching
+/// the largest tuple arity the standard library implements traits for.
+const MAX_TUPLE_ARITY: usize = 12;
+
+/// Creates a function that generates an invoke in the impl block (all methods to be invoked must
+/// share the same signature, excepting details like names, comments, etc).
+/// Note that rather than returning, the
+/// invoke  functions will share the same parameter signature as the impl block functions but
 /// also has a "consumer" of one of:
 ///     FnMut(Original Return Type)
 ///     FnMut(Enum Variant, Original Return Type)
@@ -376,22 +1552,39 @@ enum InvokeType {
 ///     FnMut(usize)
 /// Additionally, an invoke function which is specified (meaning it takes a specified list of
 /// which functions to invoke) will further take a parameter of IntoIterator
+#[allow(clippy::too_many_arguments)]
 fn create_invoke_function(
     base_method: &ImplItemMethod,
     methods: &Vec<&ImplItemMethod>,
     struct_ident: &Ident,
     invoke_type: InvokeType,
     name: &Option<String>,
+    prefix: &Option<String>,
+    verb: &Option<String>,
+    enum_name: &Ident,
+    variant_idents: &[Ident],
     clone: &Option<HashSet<usize>>,
+    consumer_modes: &HashMap<String, ConsumerMode>,
+    on_invalid: InvalidIndexMode,
 ) -> ImplItem {
     // Get output type:
     let output_type = base_method.sig.output.clone();
 
-    // Generate Ident for the name of the function
-    let invoke_name = generate_invoke_name(name, invoke_type);
+    // invoke_subset_checked always returns indices out of range, whatever on_invalid says
+    let on_invalid = if let InvokeType::CheckedSubset = invoke_type {
+        InvalidIndexMode::Error
+    } else {
+        on_invalid
+    };
 
-    // Generate Ident corresponding to enum name, in case this exists:
-    let enum_name = generate_enum_name(struct_ident, name);
+    // Look up whether results are passed to the consumer by value or by reference:
+    let consumer_mode = consumer_modes
+        .get(generate_consumer_mode_key(invoke_type))
+        .copied()
+        .unwrap_or(ConsumerMode::Owned);
+
+    // Generate Ident for the name of the function
+    let invoke_name = generate_invoke_name(name, prefix, verb, invoke_type);
 
     // Set up the signature for the invoke function being constructed.
     let mut invoke_sig = Signature {
@@ -402,25 +1595,35 @@ fn create_invoke_function(
         ..base_method.sig.clone()
     };
 
+    // The invoke function only moves self on, so it doesn't need mut self
+    if let Some(FnArg::Receiver(receiver)) = invoke_sig.inputs.first_mut() {
+        if receiver.reference.is_none() {
+            receiver.mutability = None;
+        }
+    }
+
+    // Parameters destructured by a pattern can't be forwarded as they are, so the invoke function
+    // binds them to a synthesized identifier instead
+    bind_pattern_params(&mut invoke_sig);
+
     let mut is_method = false;
 
-    // Grab parameter identifiers to invoke function before appending consumer closure parameter
+    // Grab parameter identifiers to invoke function before appending consumer closure parameter,
+    // indexing them like the clone argument does, without the receiver
     let param_ids = invoke_sig
         .inputs
         .iter()
         .cloned()
-        .enumerate()
-        .filter_map(|(index, fnarg)| match fnarg {
-            FnArg::Receiver(receiver) => {
-                if receiver.reference.is_some() {
-                    is_method = true;
-                } else {
-                    panic!("invoke_impl cannot be used with methods taking self as move!");
-                }
+        .filter_map(|fnarg| match fnarg {
+            FnArg::Receiver(_) => {
+                // Any receiver is fine here, as validate_clone_self has already rejected self by
+                // value without clone_self
+                is_method = true;
                 None
             }
-            Typed(pattype) => Some((index, pattype)),
+            Typed(pattype) => Some(pattype),
         })
+        .enumerate()
         .filter_map(|(index, pat)| match *pat.pat {
             Pat::Ident(patident) => Some({
                 let id = patident.ident;
@@ -441,18 +1644,24 @@ fn create_invoke_function(
         })
         .collect::<Vec<_>>();
 
-    // Get generic parameters
-    let generic_params = invoke_sig
-        .generics
-        .params
-        .iter()
-        .cloned()
-        .filter_map(|gp| match gp {
-            GenericParam::Type(tp) => Some(tp.ident),
-            _ => None,
-        })
+    // The same parameters without any cloning, for a call that can move them as nothing is called
+    // after it
+    let moved_param_ids = forwarded_param_idents(&invoke_sig)
+        .into_iter()
+        .map(|id| Expr::Path(syn::parse(quote!(#id).into()).unwrap()))
         .collect::<Vec<_>>();
 
+    // Get generic parameters, in declaration order, that need to be forwarded in the turbofish
+    let generic_params = forwarded_generic_params(&invoke_sig);
+
+    // If the arguments are built lazily, the factory replaces the forwarded parameters:
+    let (lazy_binding, param_ids) = if let InvokeType::LazySubset = invoke_type {
+        let (binding, forwarded) = replace_params_with_factory(&mut invoke_sig);
+        (Some(binding), forwarded)
+    } else {
+        (None, param_ids)
+    };
+
     // Specify name of closure parameter, if one will be provided:
     let closure_ident = Ident::new("consumer", Span::call_site());
 
@@ -460,26 +1669,118 @@ fn create_invoke_function(
     if output_type != generate_trailing_return_type() && output_type != ReturnType::Default {
         // Use method return type to create an impl trait definition for consumer closures
         let arg = if let ReturnType::Type(_, bx) = output_type.clone() {
-            let bxtype = *bx;
+            let bxtype = consumed_type(consumer_mode, (*bx).clone());
             match invoke_type {
                 InvokeType::Specified(st) | InvokeType::SpecifiedAll(st) => match st {
-                    SpecificationType::Enum => syn::parse(
-                        quote!(mut #closure_ident: impl FnMut(#enum_name, #bxtype)).into(),
-                    )
-                    .unwrap(),
-                    SpecificationType::Enumerated => {
+                    SpecificationType::Enum => Some(
+                        syn::parse(
+                            quote!(mut #closure_ident: impl FnMut(#enum_name, #bxtype)).into(),
+                        )
+                        .unwrap(),
+                    ),
+                    SpecificationType::Enumerated => Some(
                         syn::parse(quote!(mut #closure_ident: impl FnMut(usize, #bxtype)).into())
-                            .unwrap()
-                    }
+                            .unwrap(),
+                    ),
                 },
-                InvokeType::All | InvokeType::Subset => {
-                    syn::parse(quote!(mut #closure_ident: impl FnMut(#bxtype)).into()).unwrap()
+                InvokeType::ByName => Some(
+                    syn::parse(quote!(mut #closure_ident: impl FnMut(#enum_name, #bxtype)).into())
+                        .unwrap(),
+                ),
+                InvokeType::All
+                | InvokeType::AllRev
+                | InvokeType::AllThen
+                | InvokeType::Progress
+                | InvokeType::Subset
+                | InvokeType::UniqueSubset
+                | InvokeType::CheckedSubset
+                | InvokeType::LazySubset
+                | InvokeType::AllAsync
+                | InvokeType::AllJoin => Some(
+                    syn::parse(quote!(mut #closure_ident: impl FnMut(#bxtype)).into()).unwrap(),
+                ),
+                InvokeType::Batch => Some(
+                    syn::parse(quote!(mut #closure_ident: impl FnMut(&[#bxtype])).into()).unwrap(),
+                ),
+                // The consumer is shared between the threads running the functions, so the Send
+                // bound is spelled out here to point errors at the closure passed in
+                InvokeType::ParEach => Some(
+                    syn::parse(
+                        quote!(#closure_ident: impl FnMut(#bxtype) + ::core::marker::Send).into(),
+                    )
+                    .unwrap(),
+                ),
+                InvokeType::Until => Some(
+                    syn::parse(
+                        quote!(mut #closure_ident: impl FnMut(#bxtype) -> ::core::ops::ControlFlow<()>)
+                            .into(),
+                    )
+                    .unwrap(),
+                ),
+                InvokeType::EnumeratedUntil => Some(
+                    syn::parse(
+                        quote!(mut #closure_ident: impl FnMut(usize, #bxtype) -> ::core::ops::ControlFlow<()>)
+                            .into(),
+                    )
+                    .unwrap(),
+                ),
+                InvokeType::Partition => Some(
+                    syn::parse(
+                        quote!(mut invoke_impl_classify: impl FnMut(&#bx) -> InvokeImplKey).into(),
+                    )
+                    .unwrap(),
+                ),
+                InvokeType::Fold => {
+                    invoke_sig
+                        .inputs
+                        .push(syn::parse(quote!(invoke_impl_init: InvokeImplAcc).into()).unwrap());
+                    Some(
+                        syn::parse(
+                            quote!(mut invoke_impl_fold: impl FnMut(InvokeImplAcc, #bx) -> InvokeImplAcc)
+                                .into(),
+                        )
+                        .unwrap(),
+                    )
+                }
+                InvokeType::EnumFold => {
+                    invoke_sig
+                        .inputs
+                        .push(syn::parse(quote!(invoke_impl_init: InvokeImplAcc).into()).unwrap());
+                    Some(
+                        syn::parse(
+                            quote!(mut invoke_impl_fold: impl FnMut(InvokeImplAcc, #enum_name, #bx) -> InvokeImplAcc)
+                                .into(),
+                        )
+                        .unwrap(),
+                    )
+                }
+                InvokeType::With => {
+                    invoke_sig
+                        .inputs
+                        .push(syn::parse(quote!(invoke_impl_acc: &mut InvokeImplAcc).into()).unwrap());
+                    Some(
+                        syn::parse(quote!(mut #closure_ident: impl FnMut(&mut InvokeImplAcc, #bx)).into())
+                            .unwrap(),
+                    )
+                }
+                InvokeType::Try => {
+                    // Only Ok values make it to the consumer
+                    let (ok_type, _) = split_result_type(&output_type).unwrap();
+                    let ok_type = consumed_type(consumer_mode, ok_type);
+                    Some(
+                        syn::parse(quote!(mut #closure_ident: impl FnMut(#ok_type)).into())
+                            .unwrap(),
+                    )
                 }
+                // Results are returned rather than consumed
+                _ => None,
             }
         } else {
             panic!("Shouldn't detect an empty return after the if statement!")
         };
-        invoke_sig.inputs.push(arg);
+        if let Some(fnarg) = arg {
+            invoke_sig.inputs.push(fnarg);
+        }
     } else {
         // Closure doesn't have to take in returntype
         let arg = match invoke_type {
@@ -491,7 +1792,10 @@ fn create_invoke_function(
                     Some(syn::parse(quote!(mut #closure_ident: impl FnMut(usize)).into()).unwrap())
                 }
             },
-            InvokeType::Subset | InvokeType::All => None,
+            InvokeType::ByName => {
+                Some(syn::parse(quote!(mut #closure_ident: impl FnMut(#enum_name)).into()).unwrap())
+            }
+            _ => None,
         };
         if let Some(fnarg) = arg {
             invoke_sig.inputs.push(fnarg);
@@ -509,29 +1813,190 @@ fn create_invoke_function(
                 syn::parse(quote!(mut invoke_impl_iter: impl Iterator<Item=usize>).into()).unwrap(),
             ),
         },
-        InvokeType::Subset => Some(
+        InvokeType::Subset
+        | InvokeType::UniqueSubset
+        | InvokeType::CheckedSubset
+        | InvokeType::LazySubset => Some(
             syn::parse(quote!(mut invoke_impl_iter: impl Iterator<Item=usize>).into()).unwrap(),
         ),
-        InvokeType::All | InvokeType::SpecifiedAll(_) => None,
+        InvokeType::EnumCollect => Some(
+            syn::parse(quote!(invoke_impl_iter: impl IntoIterator<Item=#enum_name>).into())
+                .unwrap(),
+        ),
+        InvokeType::Extend => {
+            Some(syn::parse(quote!(invoke_impl_target: &mut InvokeImplTarget).into()).unwrap())
+        }
+        InvokeType::Into => {
+            let result_type = get_result_type(&output_type);
+            let count = methods.len();
+            Some(syn::parse(quote!(invoke_impl_out: &mut [#result_type; #count]).into()).unwrap())
+        }
+        InvokeType::CollectIndices => {
+            Some(syn::parse(quote!(invoke_impl_indices: &[usize]).into()).unwrap())
+        }
+        InvokeType::AllThen => {
+            Some(syn::parse(quote!(mut invoke_impl_finish: impl FnMut()).into()).unwrap())
+        }
+        InvokeType::ByName => {
+            // Lifetimes are declared ahead of any type parameters
+            invoke_sig
+                .generics
+                .params
+                .insert(0, syn::parse(quote!('invoke_impl_name).into()).unwrap());
+            Some(
+                syn::parse(
+                    quote!(invoke_impl_names: impl Iterator<Item = &'invoke_impl_name str>).into(),
+                )
+                .unwrap(),
+            )
+        }
+        InvokeType::Progress => Some(
+            syn::parse(quote!(mut invoke_impl_progress: impl FnMut(usize, usize)).into()).unwrap(),
+        ),
+        _ => None,
     };
     if let Some(fnarg) = specifier {
         invoke_sig.inputs.push(fnarg);
     }
 
+    // Set the return type of invoke functions that return results rather than consuming them:
+    let result_type = get_result_type(&output_type);
+    match invoke_type {
+        InvokeType::Tuple => {
+            let tuple_types = vec![result_type; methods.len()];
+            invoke_sig.output = syn::parse(quote!(-> (#(#tuple_types,)*)).into()).unwrap();
+        }
+        InvokeType::Fastest => {
+            invoke_sig.output =
+                syn::parse(quote!(-> (#enum_name, ::std::time::Duration, #result_type)).into())
+                    .unwrap();
+        }
+        InvokeType::FirstNonPanicking => {
+            invoke_sig.output =
+                syn::parse(quote!(-> ::core::option::Option<#result_type>).into()).unwrap();
+        }
+        InvokeType::Par | InvokeType::Collect | InvokeType::CollectIndices => {
+            invoke_sig.output =
+                syn::parse(quote!(-> ::std::vec::Vec<#result_type>).into()).unwrap();
+        }
+        InvokeType::EnumeratedCollect => {
+            invoke_sig.output =
+                syn::parse(quote!(-> ::std::vec::Vec<(usize, #result_type)>).into()).unwrap();
+        }
+        InvokeType::Deque => {
+            invoke_sig.output =
+                syn::parse(quote!(-> ::std::collections::VecDeque<#result_type>).into()).unwrap();
+        }
+        InvokeType::NamedMap => {
+            invoke_sig.output = syn::parse(
+                quote!(-> ::std::collections::HashMap<&'static str, #result_type>).into(),
+            )
+            .unwrap();
+        }
+        InvokeType::Partition => {
+            invoke_sig.generics.params.push(
+                syn::parse(quote!(InvokeImplKey: ::core::cmp::Eq + ::core::hash::Hash).into())
+                    .unwrap(),
+            );
+            invoke_sig.output = syn::parse(
+                quote!(-> ::std::collections::HashMap<InvokeImplKey, ::std::vec::Vec<(usize, #result_type)>>)
+                    .into(),
+            )
+            .unwrap();
+        }
+        InvokeType::Fold | InvokeType::EnumFold => {
+            invoke_sig
+                .generics
+                .params
+                .push(syn::parse(quote!(InvokeImplAcc).into()).unwrap());
+            invoke_sig.output = syn::parse(quote!(-> InvokeImplAcc).into()).unwrap();
+        }
+        InvokeType::With => {
+            invoke_sig
+                .generics
+                .params
+                .push(syn::parse(quote!(InvokeImplAcc).into()).unwrap());
+        }
+        InvokeType::Extend => {
+            invoke_sig.generics.params.push(
+                syn::parse(quote!(InvokeImplTarget: ::core::iter::Extend<#result_type>).into())
+                    .unwrap(),
+            );
+        }
+        InvokeType::EnumCollect => {
+            invoke_sig.output =
+                syn::parse(quote!(-> ::std::vec::Vec<(#enum_name, #result_type)>).into()).unwrap();
+        }
+        InvokeType::Results => {
+            let results_name = generate_results_name(struct_ident, name);
+            invoke_sig.output = syn::parse(quote!(-> #results_name<#result_type>).into()).unwrap();
+        }
+        InvokeType::Iter => {
+            // The iterator borrows self, if there is one
+            invoke_sig.output = if is_method {
+                syn::parse(quote!(-> impl Iterator<Item = #result_type> + '_).into()).unwrap()
+            } else {
+                syn::parse(quote!(-> impl Iterator<Item = #result_type>).into()).unwrap()
+            };
+        }
+        InvokeType::Try => {
+            let (_, err_type) = split_result_type(&output_type).unwrap();
+            invoke_sig.output =
+                syn::parse(quote!(-> ::core::result::Result<(), #err_type>).into()).unwrap();
+        }
+        InvokeType::CountOk => {
+            invoke_sig.output = syn::parse(quote!(-> usize).into()).unwrap();
+        }
+        InvokeType::FirstSome => {
+            let some_type = split_option_type(&output_type).unwrap();
+            invoke_sig.output =
+                syn::parse(quote!(-> ::core::option::Option<#some_type>).into()).unwrap();
+        }
+        InvokeType::Subset
+        | InvokeType::UniqueSubset
+        | InvokeType::CheckedSubset
+        | InvokeType::LazySubset
+        | InvokeType::Specified(SpecificationType::Enumerated)
+            if on_invalid == InvalidIndexMode::Error =>
+        {
+            invoke_sig.output =
+                syn::parse(quote!(-> ::core::result::Result<(), usize>).into()).unwrap();
+        }
+        InvokeType::ByName => {
+            invoke_sig.output =
+                syn::parse(quote!(-> ::core::result::Result<(), ::std::string::String>).into())
+                    .unwrap();
+        }
+        _ => {}
+    }
+
     // By this point, supposing the methods have signatures like pub fn name<T: Trait>(arg: T) -> r
     // The invoke function has signature like
     // pub fn invoke<T: Trait>(arg: T, mut consumer: FnMut(r) -> ()) -> ()
 
     // Attach correct body block to correct function signature:
-    let invoke_block = match invoke_type {
+    let mut invoke_block = match invoke_type {
         InvokeType::Specified(st) => invoke_enum_block(
             is_method,
             st,
             &output_type,
             methods,
             &closure_ident,
-            &struct_ident,
-            name,
+            consumer_mode,
+            enum_name,
+            variant_idents,
+            &generic_params,
+            &param_ids,
+            on_invalid,
+        ),
+        InvokeType::ByName => invoke_by_name_block(
+            is_method,
+            &output_type,
+            methods,
+            &closure_ident,
+            consumer_mode,
+            enum_name,
+            variant_idents,
             &generic_params,
             &param_ids,
         ),
@@ -541,48 +2006,272 @@ fn create_invoke_function(
             &output_type,
             methods,
             &closure_ident,
-            &struct_ident,
-            name,
+            consumer_mode,
+            enum_name,
+            variant_idents,
             &generic_params,
             &param_ids,
         ),
-        InvokeType::Subset => invoke_some_block(
+        InvokeType::Subset
+        | InvokeType::UniqueSubset
+        | InvokeType::CheckedSubset
+        | InvokeType::LazySubset => invoke_some_block(
             is_method,
             &output_type,
             methods,
             &closure_ident,
-            &struct_ident,
+            consumer_mode,
             &generic_params,
             &param_ids,
+            lazy_binding.as_ref(),
+            on_invalid,
+            matches!(invoke_type, InvokeType::UniqueSubset),
         ),
-        InvokeType::All => invoke_all_block(
+        InvokeType::All | InvokeType::AllRev | InvokeType::AllAsync => invoke_all_block(
             is_method,
             &output_type,
             methods,
             &closure_ident,
-            &struct_ident,
+            consumer_mode,
             &generic_params,
             &param_ids,
+            &moved_param_ids,
+            matches!(invoke_type, InvokeType::AllAsync),
+            matches!(invoke_type, InvokeType::AllRev),
         ),
-    };
-
-    // Combine invoke_sig and invoke_block into an actual combined function
-    ImplItem::Method(ImplItemMethod {
-        sig: invoke_sig,
-        block: invoke_block,
-        ..base_method.clone()
-    })
-}
-
-/// Generates a body block for an invoke_all function
-fn invoke_all_block(
-    is_method: bool,
-    output_type: &ReturnType,
-    methods: &Vec<&ImplItemMethod>,
-    closure_ident: &Ident,
-    struct_ident: &Ident,
-    generic_params: &Vec<Ident>,
+        InvokeType::AllThen => invoke_all_then_block(
+            is_method,
+            &output_type,
+            methods,
+            &closure_ident,
+            consumer_mode,
+            &generic_params,
+            &param_ids,
+            &moved_param_ids,
+        ),
+        InvokeType::Progress => invoke_all_progress_block(
+            is_method,
+            &output_type,
+            methods,
+            &closure_ident,
+            consumer_mode,
+            &generic_params,
+            &param_ids,
+            &moved_param_ids,
+        ),
+        InvokeType::Tuple => {
+            invoke_all_tuple_block(is_method, methods, &generic_params, &param_ids)
+        }
+        InvokeType::Batch => invoke_all_batch_block(
+            is_method,
+            methods,
+            &closure_ident,
+            &generic_params,
+            &param_ids,
+        ),
+        InvokeType::Collect => {
+            invoke_all_collect_block(is_method, methods, &generic_params, &param_ids)
+        }
+        InvokeType::EnumeratedCollect => {
+            invoke_all_enumerated_collect_block(is_method, methods, &generic_params, &param_ids)
+        }
+        InvokeType::CollectIndices => {
+            invoke_all_collect_indices_block(is_method, methods, &generic_params, &param_ids)
+        }
+        InvokeType::Deque => {
+            invoke_all_deque_block(is_method, methods, &generic_params, &param_ids)
+        }
+        InvokeType::NamedMap => {
+            invoke_all_named_map_block(is_method, methods, &generic_params, &param_ids)
+        }
+        InvokeType::Partition => {
+            invoke_all_partition_block(is_method, methods, &generic_params, &param_ids)
+        }
+        InvokeType::Fold => {
+            invoke_all_fold_block(is_method, methods, None, &generic_params, &param_ids)
+        }
+        InvokeType::EnumFold => invoke_all_fold_block(
+            is_method,
+            methods,
+            Some((enum_name, variant_idents)),
+            &generic_params,
+            &param_ids,
+        ),
+        InvokeType::With => invoke_all_with_block(
+            is_method,
+            methods,
+            &closure_ident,
+            &generic_params,
+            &param_ids,
+        ),
+        InvokeType::Extend => {
+            invoke_all_extend_block(is_method, methods, &generic_params, &param_ids)
+        }
+        InvokeType::Into => invoke_all_into_block(is_method, methods, &generic_params, &param_ids),
+        InvokeType::EnumCollect => invoke_enum_collect_block(
+            is_method,
+            methods,
+            enum_name,
+            variant_idents,
+            &generic_params,
+            &param_ids,
+        ),
+        InvokeType::Results => invoke_all_results_block(
+            is_method,
+            methods,
+            struct_ident,
+            name,
+            &generic_params,
+            &param_ids,
+        ),
+        InvokeType::FirstNonPanicking => {
+            invoke_first_non_panicking_block(is_method, methods, &generic_params, &param_ids)
+        }
+        InvokeType::FirstSome => {
+            invoke_all_first_some_block(is_method, methods, &generic_params, &param_ids)
+        }
+        InvokeType::Fastest => invoke_all_fastest_block(
+            is_method,
+            methods,
+            enum_name,
+            variant_idents,
+            &generic_params,
+            &param_ids,
+        ),
+        InvokeType::AllJoin => invoke_all_join_block(
+            is_method,
+            &output_type,
+            methods,
+            &closure_ident,
+            consumer_mode,
+            &generic_params,
+            &param_ids,
+        ),
+        InvokeType::Iter => invoke_all_iter_block(
+            is_method,
+            &output_type,
+            methods,
+            struct_ident,
+            name,
+            &generic_params,
+            &param_ids,
+        ),
+        InvokeType::Par => invoke_all_par_block(is_method, methods, &generic_params, &param_ids),
+        InvokeType::ParEach => invoke_all_par_each_block(
+            is_method,
+            methods,
+            &closure_ident,
+            consumer_mode,
+            &generic_params,
+            &param_ids,
+        ),
+        InvokeType::Until | InvokeType::EnumeratedUntil => invoke_all_until_block(
+            is_method,
+            methods,
+            &closure_ident,
+            consumer_mode,
+            &generic_params,
+            &param_ids,
+            matches!(invoke_type, InvokeType::EnumeratedUntil),
+        ),
+        InvokeType::Try => invoke_all_try_block(
+            is_method,
+            methods,
+            &closure_ident,
+            consumer_mode,
+            &generic_params,
+            &param_ids,
+        ),
+        InvokeType::CountOk => {
+            invoke_all_count_ok_block(is_method, methods, &generic_params, &param_ids)
+        }
+    };
+
+    // Methods taking self by value get clones of self, except for the last call of invoke functions
+    // calling every function once in order, which can move self itself
+    if has_value_receiver(&base_method.sig) {
+        let move_last = matches!(
+            invoke_type,
+            InvokeType::All
+                | InvokeType::AllRev
+                | InvokeType::AllThen
+                | InvokeType::Progress
+                | InvokeType::SpecifiedAll(_)
+                | InvokeType::Tuple
+                | InvokeType::Batch
+                | InvokeType::Collect
+                | InvokeType::EnumeratedCollect
+                | InvokeType::Deque
+                | InvokeType::NamedMap
+                | InvokeType::CollectIndices
+                | InvokeType::Results
+                | InvokeType::Partition
+                | InvokeType::Extend
+                | InvokeType::Into
+                | InvokeType::Fold
+                | InvokeType::EnumFold
+                | InvokeType::With
+                | InvokeType::Fastest
+                | InvokeType::FirstNonPanicking
+                | InvokeType::FirstSome
+                | InvokeType::Until
+                | InvokeType::EnumeratedUntil
+                | InvokeType::Try
+                | InvokeType::CountOk
+                | InvokeType::AllAsync
+        );
+        clone_self_in_calls(&mut invoke_block, move_last);
+    }
+
+    // The invoke function of unsafe functions is unsafe itself, and calls them in an unsafe block
+    if invoke_sig.unsafety.is_some() {
+        invoke_block = syn::parse(quote!({ unsafe #invoke_block }).into()).unwrap();
+    }
+
+    // Combine invoke_sig and invoke_block into an actual combined function
+    // The attributes of base_method, such as its doc comments, #[inline] or #[cfg]s, describe it
+    // rather than the invoke function, so the invoke function only gets a doc comment of its own.
+    // The closures and iterators it appends to the parameters of the functions can push it past
+    // clippy's too_many_arguments, which downstream crates shouldn't have to answer for.
+    let doc = generate_invoke_doc(invoke_type);
+    let mut attrs: Vec<Attribute> = vec![
+        syn::parse_quote!(#[doc = #doc]),
+        syn::parse_quote!(#[allow(clippy::too_many_arguments)]),
+    ];
+    if invoke_sig.unsafety.is_some() {
+        attrs.push(syn::parse_quote!(#[doc = ""]));
+        attrs.push(syn::parse_quote!(#[doc = " # Safety"]));
+        attrs.push(syn::parse_quote!(#[doc = ""]));
+        attrs.push(syn::parse_quote!(
+            #[doc = " Callers must uphold the safety requirements of every function invoked."]
+        ));
+    }
+
+    ImplItem::Method(ImplItemMethod {
+        attrs,
+        sig: invoke_sig,
+        block: invoke_block,
+        ..base_method.clone()
+    })
+}
+
+/// Generates a body block for an invoke_all function. If awaited is true, each call is awaited (as
+/// for invoke_all_async), and if reversed is true, the functions are called in reverse impl block
+/// order (as for invoke_all_rev). The last function called is passed moved_param_ids, which forward
+/// the parameters without cloning them, unless it is behind a #[cfg], as the function it follows
+/// might then be the last one compiled in.
+#[allow(clippy::too_many_arguments)]
+fn invoke_all_block(
+    is_method: bool,
+    output_type: &ReturnType,
+    methods: &Vec<&ImplItemMethod>,
+    closure_ident: &Ident,
+    consumer_mode: ConsumerMode,
+    generic_params: &Vec<Ident>,
     param_ids: &Vec<Expr>,
+    moved_param_ids: &Vec<Expr>,
+    awaited: bool,
+    reversed: bool,
 ) -> Block {
     // Set up body block for the invoke  method:
     let mut invoke_block = Block {
@@ -590,349 +2279,2087 @@ fn invoke_all_block(
         stmts: vec![],
     };
 
-    // Iterating over names, call consumer to consume a call of a given function:
-    for &method in methods {
-        // Call function with forwarded parameters
-        let inner_call =
-            get_inner_call_expr(is_method, method, struct_ident, generic_params, param_ids);
+    // Iterating over names (backwards if reversed), call consumer to consume a call of a given
+    // function:
+    let ordered: Vec<&ImplItemMethod> = if reversed {
+        methods.iter().rev().copied().collect()
+    } else {
+        methods.to_vec()
+    };
+    for (index, &method) in ordered.iter().enumerate() {
+        let cfgs = cfg_attrs(method);
+
+        // Call function with forwarded parameters, moving them into the last call
+        let param_ids = if index + 1 == ordered.len() && cfgs.is_empty() {
+            moved_param_ids
+        } else {
+            param_ids
+        };
+        let inner_call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+        let inner_call = if awaited {
+            syn::parse(quote!(#inner_call.await).into()).unwrap()
+        } else {
+            inner_call
+        };
+        let consumed_call = consumed_expr(consumer_mode, &inner_call);
 
         if output_type != &generate_trailing_return_type() && output_type != &ReturnType::Default {
             // Functions have return type, so the invoke_all function accepts a closure
 
-            // Insert previous call into a call of consumer:
-            let outer_call: ExprCall =
-                syn::parse(quote!(#closure_ident(#inner_call)).into()).unwrap();
-
-            // Insert combined call into statements
-            invoke_block
-                .stmts
-                .push(Stmt::Semi(Expr::Call(outer_call), Default::default()));
+            // Insert previous call into a call of consumer, and the combined call into statements
+            invoke_block.stmts.push(
+                syn::parse(quote!(#(#cfgs)* #closure_ident(#consumed_call);).into()).unwrap(),
+            );
         } else {
             // Only need to insert inner call
             invoke_block
                 .stmts
-                .push(Stmt::Semi(inner_call, Default::default()));
+                .push(syn::parse(quote!(#(#cfgs)* #inner_call;).into()).unwrap());
         }
     }
     invoke_block
 }
 
-/// Generates a body block for the invoke_subset function
-fn invoke_some_block(
+/// Generates a body block for the invoke_all_then function, which calls every function like
+/// invoke_all and then calls invoke_impl_finish once
+#[allow(clippy::too_many_arguments)]
+fn invoke_all_then_block(
     is_method: bool,
     output_type: &ReturnType,
     methods: &Vec<&ImplItemMethod>,
     closure_ident: &Ident,
-    struct_ident: &Ident,
+    consumer_mode: ConsumerMode,
     generic_params: &Vec<Ident>,
     param_ids: &Vec<Expr>,
+    moved_param_ids: &Vec<Expr>,
 ) -> Block {
-    // Set up body block for the invoke  method:
-    let mut invoke_block = Block {
-        brace_token: Default::default(),
-        stmts: vec![],
-    };
-
-    // Set up inner match statement
-    let mut match_statement: ExprMatch = syn::parse(quote!(match invoke_impl_i {}).into()).unwrap();
-
-    // Iterate over methods, generating match arms:
-    for (index, &method) in methods.into_iter().enumerate() {
-        // Get inner call
-        let inner_call =
-            get_inner_call_expr(is_method, method, struct_ident, generic_params, param_ids);
-
-        // Convert/merge to outer call
-        let outer_call = if output_type != &generate_trailing_return_type()
-            && output_type != &ReturnType::Default
-        {
-            // Functions have return type, so the invoke_subset function accepts a closure
-            // Insert previous call into a call of consumer:
-            syn::parse(quote!(#closure_ident(#inner_call)).into()).unwrap()
-        } else {
-            // Only want to call the inner function in this case
-            inner_call
-        };
-
-        // Parse to match arm
-        match_statement
-            .arms
-            .push(syn::parse(quote!(#index => #outer_call,).into()).unwrap());
-    }
-
-    // Add default case to match statement
-    match_statement.arms.push(
-        syn::parse(quote!(_ => panic!("Iter contains invalid function index!")).into()).unwrap(),
+    let mut invoke_block = invoke_all_block(
+        is_method,
+        output_type,
+        methods,
+        closure_ident,
+        consumer_mode,
+        generic_params,
+        param_ids,
+        moved_param_ids,
+        false,
+        false,
     );
-
-    // Wrap match in loop
-    let loopexpr: ExprForLoop = syn::parse(
-        quote!(for invoke_impl_i in invoke_impl_iter {
-            #match_statement
-        })
-        .into(),
-    )
-    .unwrap();
-
-    // Add loop to block
-    invoke_block.stmts.push(Stmt::Expr(Expr::ForLoop(loopexpr)));
-
+    invoke_block
+        .stmts
+        .push(syn::parse(quote!(invoke_impl_finish();).into()).unwrap());
     invoke_block
 }
 
-/// Generates bodies for invoke_all_enum and invoke_all_enumerated
-fn invoke_all_enum_block(
+/// Generates a body block for the invoke_all_progress function, which calls every function like
+/// invoke_all, following each call with a call of invoke_impl_progress, passing it the number of
+/// functions called so far and the number of functions in the impl block. The invoke_impl_progress
+/// call of a function behind a #[cfg] shares its #[cfg].
+#[allow(clippy::too_many_arguments)]
+fn invoke_all_progress_block(
     is_method: bool,
-    specification_type: SpecificationType,
     output_type: &ReturnType,
     methods: &Vec<&ImplItemMethod>,
     closure_ident: &Ident,
-    struct_ident: &Ident,
-    name: &Option<String>,
+    consumer_mode: ConsumerMode,
     generic_params: &Vec<Ident>,
     param_ids: &Vec<Expr>,
+    moved_param_ids: &Vec<Expr>,
 ) -> Block {
-    // Set up body block for the invoke  method:
+    let invoke_all = invoke_all_block(
+        is_method,
+        output_type,
+        methods,
+        closure_ident,
+        consumer_mode,
+        generic_params,
+        param_ids,
+        moved_param_ids,
+        false,
+        false,
+    );
+
+    // invoke_all_block generates one statement per function, in impl block order
+    let total = methods.len();
     let mut invoke_block = Block {
         brace_token: Default::default(),
         stmts: vec![],
     };
+    for (index, (stmt, &method)) in invoke_all.stmts.into_iter().zip(methods).enumerate() {
+        let cfgs = cfg_attrs(method);
+        let completed = index + 1;
+        invoke_block.stmts.push(stmt);
+        invoke_block.stmts.push(
+            syn::parse(quote!(#(#cfgs)* invoke_impl_progress(#completed, #total);).into()).unwrap(),
+        );
+    }
+    invoke_block
+}
 
-    // Generate enum name
-    let enum_name = generate_enum_name(struct_ident, name);
-
-    // Generate list of idents that enum has:
-    let identifiers = methods
-        .into_iter()
-        .map(|im| im.sig.ident.clone())
+/// Generates a body block for the invoke_all_tuple function, which evaluates to a tuple of the
+/// results of every function in impl block order
+fn invoke_all_tuple_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    // Gather a call of each function
+    let calls = methods
+        .iter()
+        .map(|&method| get_inner_call_expr(is_method, method, generic_params, param_ids))
         .collect::<Vec<_>>();
 
-    for (index, (enum_ident, &method)) in
-        identifiers.into_iter().zip(methods.into_iter()).enumerate()
-    {
-        // Get inner call
-        let inner_call =
-            get_inner_call_expr(is_method, method, struct_ident, generic_params, param_ids);
-
-        // Convert/merge to outer call
-        let outer_call = if output_type != &generate_trailing_return_type()
-            && output_type != &ReturnType::Default
-        {
-            // Functions have return type, so the invoke function accepts a closure with returntype
-            // Insert previous call into a call of consumer:
-            match specification_type {
-                SpecificationType::Enum => {
-                    syn::parse(quote!(#closure_ident(#enum_name::#enum_ident, #inner_call)).into())
-                        .unwrap()
-                }
-                SpecificationType::Enumerated => {
-                    syn::parse(quote!(#closure_ident(#index, #inner_call)).into()).unwrap()
-                }
-            }
-        } else {
-            // Closure takes iteration type; so call inner first and then call closure:
-            invoke_block
-                .stmts
-                .push(Stmt::Semi(inner_call, Default::default()));
-            match specification_type {
-                SpecificationType::Enum => {
-                    syn::parse(quote!(#closure_ident(#enum_name::#enum_ident)).into()).unwrap()
-                }
-                SpecificationType::Enumerated => {
-                    syn::parse(quote!(#closure_ident(#index)).into()).unwrap()
-                }
-            }
-        };
+    // Trailing comma ensures a single call still produces a one element tuple
+    let tuple: Expr = syn::parse(quote!((#(#calls,)*)).into()).unwrap();
 
-        // Add outer call to block
-        invoke_block
-            .stmts
-            .push(Stmt::Semi(Expr::Call(outer_call), Default::default()));
+    Block {
+        brace_token: Default::default(),
+        stmts: vec![Stmt::Expr(tuple)],
     }
-
-    invoke_block
 }
 
-/// Generates bodies for invoke_enum and invoke_enumerated
-fn invoke_enum_block(
+/// Generates a body block for the invoke_all_batch function, which collects the results of every
+/// function in impl block order into an array and passes it to the consumer as a slice
+fn invoke_all_batch_block(
     is_method: bool,
-    specification_type: SpecificationType,
-    output_type: &ReturnType,
     methods: &Vec<&ImplItemMethod>,
     closure_ident: &Ident,
-    struct_ident: &Ident,
-    name: &Option<String>,
     generic_params: &Vec<Ident>,
     param_ids: &Vec<Expr>,
 ) -> Block {
-    // Set up body block for the invoke  method:
-    let mut invoke_block = Block {
-        brace_token: Default::default(),
-        stmts: vec![],
-    };
+    // Gather a call of each function, along with its cfgs
+    let calls = methods
+        .iter()
+        .map(|&method| get_inner_call_expr(is_method, method, generic_params, param_ids))
+        .collect::<Vec<_>>();
+    let cfgs = methods
+        .iter()
+        .map(|&method| cfg_attrs(method))
+        .collect::<Vec<_>>();
 
-    // Generate enum name
-    let enum_name = generate_enum_name(struct_ident, name);
+    syn::parse(
+        quote!({
+            let invoke_impl_results = [#(#(#cfgs)* #calls),*];
+            #closure_ident(&invoke_impl_results);
+        })
+        .into(),
+    )
+    .unwrap()
+}
 
-    // Generate list of idents that enum has:
-    let identifiers = methods
-        .into_iter()
-        .map(|im| im.sig.ident.clone())
+/// Generates a body block for the invoke_all_collect function, which evaluates to a Vec of the
+/// results of every function in impl block order
+fn invoke_all_collect_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    // Gather a call of each function, along with its cfgs
+    let calls = methods
+        .iter()
+        .map(|&method| get_inner_call_expr(is_method, method, generic_params, param_ids))
+        .collect::<Vec<_>>();
+    let cfgs = methods
+        .iter()
+        .map(|&method| cfg_attrs(method))
         .collect::<Vec<_>>();
 
-    // Set up inner match statement
+    // Built from an array rather than with vec!, so the calls stay visible to clone_self_in_calls
+    let vec: Expr =
+        syn::parse(quote!(::std::vec::Vec::from([#(#(#cfgs)* #calls),*])).into()).unwrap();
+
+    Block {
+        brace_token: Default::default(),
+        stmts: vec![Stmt::Expr(vec)],
+    }
+}
+
+/// Generates a body block for the invoke_all_enumerated_collect function, which evaluates to a Vec
+/// of the results of every function in impl block order, each paired with the index of its function
+fn invoke_all_enumerated_collect_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    // Pair a call of each function with its index, along with its cfgs
+    let pairs = methods.iter().enumerate().map(|(index, &method)| {
+        let cfgs = cfg_attrs(method);
+        let call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+        quote!(#(#cfgs)* (#index, #call))
+    });
+
+    // Built from an array like that of invoke_all_collect
+    syn::parse(quote!({ ::std::vec::Vec::from([#(#pairs),*]) }).into()).unwrap()
+}
+
+/// Generates a body block for the invoke_all_collect_indices function, which calls every function
+/// in impl block order but only keeps the results of those whose index is in invoke_impl_indices,
+/// evaluating to a Vec of them in impl block order
+fn invoke_all_collect_indices_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    // Keep the result of each function only if its index was asked for
+    let calls = methods
+        .iter()
+        .enumerate()
+        .map(|(index, &method)| {
+            let inner_call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+            let cfgs = cfg_attrs(method);
+            quote!(#(#cfgs)* {
+                let invoke_impl_result = #inner_call;
+                if invoke_impl_indices.contains(&#index) {
+                    invoke_impl_results.push(invoke_impl_result);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    syn::parse(
+        quote!({
+            let mut invoke_impl_results = ::std::vec::Vec::with_capacity(invoke_impl_indices.len());
+            #(#calls)*
+            invoke_impl_results
+        })
+        .into(),
+    )
+    .unwrap()
+}
+
+/// Generates a body block for the invoke_all_deque function, which converts the Vec built like that
+/// of invoke_all_collect into a VecDeque, reusing its buffer
+fn invoke_all_deque_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    let collected = invoke_all_collect_block(is_method, methods, generic_params, param_ids);
+    syn::parse(quote!({ ::std::collections::VecDeque::from(#collected) }).into()).unwrap()
+}
+
+/// Generates a body block for the invoke_all_named_map function, which evaluates to a HashMap of
+/// the results of every function, keyed by the names of the functions as in METHOD_LIST
+fn invoke_all_named_map_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    // Insert the result of each function under its name, along with its cfgs
+    let inserts = methods.iter().map(|&method| {
+        let cfgs = cfg_attrs(method);
+        let name = method.sig.ident.to_string();
+        let call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+        quote!(#(#cfgs)* invoke_impl_map.insert(#name, #call);)
+    });
+    let count = methods.len();
+
+    syn::parse(
+        quote!({
+            let mut invoke_impl_map = ::std::collections::HashMap::with_capacity(#count);
+            #(#inserts)*
+            invoke_impl_map
+        })
+        .into(),
+    )
+    .unwrap()
+}
+
+/// Generates a body block for the invoke_all_results function, which wraps the Vec built like that
+/// of invoke_all_collect in the struct generated by create_results_struct
+fn invoke_all_results_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    struct_ident: &Ident,
+    name: &Option<String>,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    let collected = invoke_all_collect_block(is_method, methods, generic_params, param_ids);
+    let results_name = generate_results_name(struct_ident, name);
+    syn::parse(quote!({ #results_name(#collected) }).into()).unwrap()
+}
+
+/// Generates a body block for the invoke_first_non_panicking function, which calls each function in
+/// impl block order inside catch_unwind and returns the result of the first one that doesn't
+/// panic, evaluating to None if they all do
+fn invoke_first_non_panicking_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    let mut invoke_block = Block {
+        brace_token: Default::default(),
+        stmts: vec![],
+    };
+    for &method in methods {
+        let inner_call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+        let cfgs = cfg_attrs(method);
+        invoke_block.stmts.push(
+            syn::parse(
+                quote!(
+                    #(#cfgs)*
+                    if let ::core::result::Result::Ok(invoke_impl_result) =
+                        ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| #inner_call))
+                    {
+                        return ::core::option::Option::Some(invoke_impl_result);
+                    }
+                )
+                .into(),
+            )
+            .unwrap(),
+        );
+    }
+    invoke_block.stmts.push(Stmt::Expr(
+        syn::parse(quote!(::core::option::Option::None).into()).unwrap(),
+    ));
+    invoke_block
+}
+
+/// Generates a body block for the invoke_all_first_some function, which calls each function in
+/// impl block order and returns the first Some one of them returns, evaluating to None if none do
+fn invoke_all_first_some_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    let mut invoke_block = Block {
+        brace_token: Default::default(),
+        stmts: vec![],
+    };
+    for &method in methods {
+        let inner_call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+        let cfgs = cfg_attrs(method);
+        invoke_block.stmts.push(
+            syn::parse(
+                quote!(
+                    #(#cfgs)*
+                    if let ::core::option::Option::Some(invoke_impl_result) = #inner_call {
+                        return ::core::option::Option::Some(invoke_impl_result);
+                    }
+                )
+                .into(),
+            )
+            .unwrap(),
+        );
+    }
+    invoke_block.stmts.push(Stmt::Expr(
+        syn::parse(quote!(::core::option::Option::None).into()).unwrap(),
+    ));
+    invoke_block
+}
+
+/// Generates a body block for the invoke_all_fastest function, which times each function in impl
+/// block order and evaluates to the enum variant, duration and result of the fastest one. Ties go
+/// to the function declared first.
+fn invoke_all_fastest_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    enum_name: &Ident,
+    variant_idents: &[Ident],
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    // Set up body block for the invoke  method:
+    let mut invoke_block = Block {
+        brace_token: Default::default(),
+        stmts: vec![],
+    };
+
+    for (index, (&method, enum_ident)) in methods.iter().zip(variant_idents).enumerate() {
+        // Get inner call
+        let inner_call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+
+        // Time the call
+        invoke_block.stmts.push(
+            syn::parse(quote!(let invoke_impl_start = ::std::time::Instant::now();).into())
+                .unwrap(),
+        );
+        invoke_block
+            .stmts
+            .push(syn::parse(quote!(let invoke_impl_result = #inner_call;).into()).unwrap());
+        invoke_block.stmts.push(
+            syn::parse(quote!(let invoke_impl_elapsed = invoke_impl_start.elapsed();).into())
+                .unwrap(),
+        );
+
+        // The first function is the fastest so far, every other one has to beat it
+        let candidate = quote!((#enum_name::#enum_ident, invoke_impl_elapsed, invoke_impl_result));
+        let update: Stmt = if index == 0 {
+            syn::parse(quote!(let mut invoke_impl_fastest = #candidate;).into()).unwrap()
+        } else {
+            syn::parse(
+                quote!(if invoke_impl_elapsed < invoke_impl_fastest.1 {
+                    invoke_impl_fastest = #candidate;
+                })
+                .into(),
+            )
+            .unwrap()
+        };
+        invoke_block.stmts.push(update);
+    }
+
+    invoke_block.stmts.push(Stmt::Expr(
+        syn::parse(quote!(invoke_impl_fastest).into()).unwrap(),
+    ));
+    invoke_block
+}
+
+/// Generates a body block for the invoke_all_join function, which awaits the futures of all
+/// functions in impl block concurrently and then passes their results to the consumer in impl
+/// block order. Every async function returns a future of a different type, so they are joined
+/// with futures::join! rather than collected for futures::future::join_all, which would need to
+/// box them.
+#[allow(clippy::too_many_arguments)]
+fn invoke_all_join_block(
+    is_method: bool,
+    output_type: &ReturnType,
+    methods: &Vec<&ImplItemMethod>,
+    closure_ident: &Ident,
+    consumer_mode: ConsumerMode,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    // Set up body block for the invoke  method:
+    let mut invoke_block = Block {
+        brace_token: Default::default(),
+        stmts: vec![],
+    };
+
+    // Gather the future of each function
+    let calls = methods
+        .iter()
+        .map(|&method| get_inner_call_expr(is_method, method, generic_params, param_ids))
+        .collect::<Vec<_>>();
+
+    if has_return_type(output_type) {
+        // Bind each result, then pass them to consumer in order
+        let results = (0..methods.len())
+            .map(|index| format_ident!("invoke_impl_result_{}", index))
+            .collect::<Vec<_>>();
+        invoke_block.stmts.push(
+            syn::parse(quote!(let (#(#results,)*) = ::futures::join!(#(#calls),*);).into())
+                .unwrap(),
+        );
+        for result in results {
+            let result = Expr::Path(syn::parse(quote!(#result).into()).unwrap());
+            let consumed_result = consumed_expr(consumer_mode, &result);
+            invoke_block
+                .stmts
+                .push(syn::parse(quote!(#closure_ident(#consumed_result);).into()).unwrap());
+        }
+    } else {
+        // Only need to await the futures
+        invoke_block
+            .stmts
+            .push(syn::parse(quote!(::futures::join!(#(#calls),*);).into()).unwrap());
+    }
+    invoke_block
+}
+
+/// Generates a body block for the invoke_all_par function, which spawns every function in impl
+/// block onto a rayon scope and evaluates to a Vec of their results in impl block order. Each
+/// spawned function receives its own clones of the arguments, as given by param_ids.
+fn invoke_all_par_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    // Inside the spawned closures the clones are forwarded as they are
+    let arg_idents = forwarded_param_idents(&methods[0].sig);
+    let moved_ids = arg_idents
+        .iter()
+        .map(|id| Expr::Path(syn::parse(quote!(#id).into()).unwrap()))
+        .collect::<Vec<_>>();
+
+    let results = (0..methods.len())
+        .map(|index| format_ident!("invoke_impl_result_{}", index))
+        .collect::<Vec<_>>();
+    let cfgs = methods
+        .iter()
+        .map(|&method| cfg_attrs(method))
+        .collect::<Vec<_>>();
+
+    // Spawn each function with its own clones of the arguments, writing its result to its slot
+    let spawns = methods
+        .iter()
+        .zip(results.iter())
+        .map(|(&method, result)| {
+            let inner_call = get_inner_call_expr(is_method, method, generic_params, &moved_ids);
+            let method_cfgs = cfg_attrs(method);
+            quote!(#(#method_cfgs)* {
+                let (#(#arg_idents,)*) = (#(#param_ids,)*);
+                let invoke_impl_slot = &mut #result;
+                invoke_impl_scope.spawn(move |_| {
+                    *invoke_impl_slot = ::core::option::Option::Some(#inner_call);
+                });
+            })
+        })
+        .collect::<Vec<_>>();
+
+    syn::parse(
+        quote!({
+            #(#(#cfgs)* let mut #results = ::core::option::Option::None;)*
+            ::rayon::scope(|invoke_impl_scope| {
+                #(#spawns)*
+            });
+            ::std::vec![#(#(#cfgs)* #results.unwrap()),*]
+        })
+        .into(),
+    )
+    .unwrap()
+}
+
+/// Generates a body block for the invoke_all_par_each function, which spawns every function in impl
+/// block onto a rayon scope like invoke_all_par_block, but passes each result to the consumer as
+/// soon as its function returns. The consumer is behind a Mutex, so only one thread calls it at a
+/// time, in whatever order the functions finish.
+fn invoke_all_par_each_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    closure_ident: &Ident,
+    consumer_mode: ConsumerMode,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    // Inside the spawned closures the clones are forwarded as they are
+    let arg_idents = forwarded_param_idents(&methods[0].sig);
+    let moved_ids = arg_idents
+        .iter()
+        .map(|id| Expr::Path(syn::parse(quote!(#id).into()).unwrap()))
+        .collect::<Vec<_>>();
+
+    let result = Expr::Path(syn::parse(quote!(invoke_impl_result).into()).unwrap());
+    let consumed_result = consumed_expr(consumer_mode, &result);
+
+    // Spawn each function with its own clones of the arguments, consuming its result in its thread
+    let spawns = methods
+        .iter()
+        .map(|&method| {
+            let inner_call = get_inner_call_expr(is_method, method, generic_params, &moved_ids);
+            let method_cfgs = cfg_attrs(method);
+            quote!(#(#method_cfgs)* {
+                let (#(#arg_idents,)*) = (#(#param_ids,)*);
+                let invoke_impl_consumer = &invoke_impl_consumer;
+                invoke_impl_scope.spawn(move |_| {
+                    let #result = #inner_call;
+                    (*invoke_impl_consumer.lock().unwrap())(#consumed_result);
+                });
+            })
+        })
+        .collect::<Vec<_>>();
+
+    syn::parse(
+        quote!({
+            let invoke_impl_consumer = ::std::sync::Mutex::new(#closure_ident);
+            ::rayon::scope(|invoke_impl_scope| {
+                #(#spawns)*
+            });
+        })
+        .into(),
+    )
+    .unwrap()
+}
+
+/// Generates a body block for the invoke_all_iter function, which boxes a thunk calling each
+/// function in impl block with its own copy of the arguments (as given by param_ids) and wraps
+/// them in the iterator struct generated by create_iter_struct.
+fn invoke_all_iter_block(
+    is_method: bool,
+    output_type: &ReturnType,
+    methods: &Vec<&ImplItemMethod>,
+    struct_ident: &Ident,
+    name: &Option<String>,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    // Inside the thunks the copies are forwarded as they are
+    let arg_idents = forwarded_param_idents(&methods[0].sig);
+    let moved_ids = arg_idents
+        .iter()
+        .map(|id| Expr::Path(syn::parse(quote!(#id).into()).unwrap()))
+        .collect::<Vec<_>>();
+
+    let thunks = methods
+        .iter()
+        .map(|&method| {
+            let inner_call = get_inner_call_expr(is_method, method, generic_params, &moved_ids);
+            quote!({
+                let (#(#arg_idents,)*) = (#(#param_ids,)*);
+                ::std::boxed::Box::new(move || #inner_call)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let result_type = get_result_type(output_type);
+    let count = methods.len();
+    let iter_name = generate_iter_name(struct_ident, name);
+    syn::parse(
+        quote!({
+            let invoke_impl_thunks: [::std::boxed::Box<dyn FnOnce() -> #result_type + '_>; #count] =
+                [#(#thunks),*];
+            #iter_name(::core::iter::IntoIterator::into_iter(invoke_impl_thunks))
+        })
+        .into(),
+    )
+    .unwrap()
+}
+
+/// Generates a body block for the invoke_all_until function, which passes the result of each
+/// function to the consumer and returns as soon as the consumer breaks, skipping the rest. If
+/// enumerated is set, the index of each function is passed to the consumer first (as for
+/// invoke_all_enumerated_until).
+fn invoke_all_until_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    closure_ident: &Ident,
+    consumer_mode: ConsumerMode,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+    enumerated: bool,
+) -> Block {
+    // Set up body block for the invoke  method:
+    let mut invoke_block = Block {
+        brace_token: Default::default(),
+        stmts: vec![],
+    };
+
+    for (index, &method) in methods.iter().enumerate() {
+        let inner_call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+        let consumed_call = consumed_expr(consumer_mode, &inner_call);
+        let index = enumerated.then(|| quote!(#index,));
+        let cfgs = cfg_attrs(method);
+        invoke_block.stmts.push(
+            syn::parse(
+                quote!(
+                    #(#cfgs)*
+                    if let ::core::ops::ControlFlow::Break(()) = #closure_ident(#index #consumed_call) {
+                        return;
+                    }
+                )
+                .into(),
+            )
+            .unwrap(),
+        );
+    }
+    invoke_block
+}
+
+/// Generates a body block for the invoke_all_try function, which passes the Ok value of each
+/// function to the consumer and returns the first Err encountered, skipping the rest
+fn invoke_all_try_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    closure_ident: &Ident,
+    consumer_mode: ConsumerMode,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    // Set up body block for the invoke  method:
+    let mut invoke_block = Block {
+        brace_token: Default::default(),
+        stmts: vec![],
+    };
+
+    for &method in methods {
+        // Propagate an Err, otherwise consume the Ok value
+        let inner_call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+        let ok_value: Expr = syn::parse(quote!(#inner_call?).into()).unwrap();
+        let consumed_value = consumed_expr(consumer_mode, &ok_value);
+        let cfgs = cfg_attrs(method);
+        invoke_block
+            .stmts
+            .push(syn::parse(quote!(#(#cfgs)* #closure_ident(#consumed_value);).into()).unwrap());
+    }
+
+    invoke_block.stmts.push(Stmt::Expr(
+        syn::parse(quote!(::core::result::Result::Ok(())).into()).unwrap(),
+    ));
+    invoke_block
+}
+
+/// Generates a body block for the invoke_all_count_ok function, which calls every function in impl
+/// block order, whether or not the ones before failed, and evaluates to how many returned Ok
+fn invoke_all_count_ok_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    let counts = methods.iter().map(|&method| {
+        let inner_call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+        let cfgs = cfg_attrs(method);
+        quote!(#(#cfgs)* {
+            if ::core::result::Result::is_ok(&#inner_call) {
+                invoke_impl_count += 1;
+            }
+        })
+    });
+
+    syn::parse(
+        quote!({
+            let mut invoke_impl_count = 0;
+            #(#counts)*
+            invoke_impl_count
+        })
+        .into(),
+    )
+    .unwrap()
+}
+
+/// Generates a body block for the invoke_subset function. If lazy_binding is provided, each match
+/// arm first binds the arguments built by the factory for its index to it (as for
+/// invoke_subset_lazy). If unique is set, indices already seen are skipped (as for
+/// invoke_subset_unique). Parameters marked for cloning are cloned in every arm, including the last
+/// function's, as the iterator may select any function more than once.
+#[allow(clippy::too_many_arguments)]
+fn invoke_some_block(
+    is_method: bool,
+    output_type: &ReturnType,
+    methods: &Vec<&ImplItemMethod>,
+    closure_ident: &Ident,
+    consumer_mode: ConsumerMode,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+    lazy_binding: Option<&Pat>,
+    on_invalid: InvalidIndexMode,
+    unique: bool,
+) -> Block {
+    // Set up body block for the invoke  method:
+    let mut invoke_block = Block {
+        brace_token: Default::default(),
+        stmts: vec![],
+    };
+
+    // Set up inner match statement
+    let mut match_statement: ExprMatch = syn::parse(quote!(match invoke_impl_i {}).into()).unwrap();
+
+    // Iterate over methods, generating match arms:
+    for (index, &method) in methods.iter().enumerate() {
+        // Get inner call
+        let inner_call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+
+        // Convert/merge to outer call
+        let outer_call = if output_type != &generate_trailing_return_type()
+            && output_type != &ReturnType::Default
+        {
+            // Functions have return type, so the invoke_subset function accepts a closure
+            // Insert previous call into a call of consumer:
+            let consumed_call = consumed_expr(consumer_mode, &inner_call);
+            syn::parse(quote!(#closure_ident(#consumed_call)).into()).unwrap()
+        } else {
+            // Only want to call the inner function in this case
+            inner_call
+        };
+
+        // Build the arguments for this function first, if they are built lazily
+        let outer_call: Expr = if let Some(binding) = lazy_binding {
+            let factory_ident = Ident::new("factory", Span::call_site());
+            syn::parse(
+                quote!({
+                    let #binding = #factory_ident(#index);
+                    #outer_call
+                })
+                .into(),
+            )
+            .unwrap()
+        } else {
+            outer_call
+        };
+
+        // Parse to match arm
+        let cfgs = cfg_attrs(method);
+        match_statement
+            .arms
+            .push(syn::parse(quote!(#(#cfgs)* #index => #outer_call,).into()).unwrap());
+    }
+
+    // Add default case to match statement
+    match_statement.arms.push(invalid_index_arm(on_invalid));
+
+    // Wrap match in loop, skipping indices already invoked if they should be unique
+    let loopexpr: ExprForLoop = if unique {
+        invoke_block.stmts.push(
+            syn::parse(
+                quote!(let mut invoke_impl_seen = ::std::collections::HashSet::new();).into(),
+            )
+            .unwrap(),
+        );
+        syn::parse(
+            quote!(for invoke_impl_i in invoke_impl_iter {
+                if !invoke_impl_seen.insert(invoke_impl_i) {
+                    continue;
+                }
+                #match_statement
+            })
+            .into(),
+        )
+        .unwrap()
+    } else {
+        syn::parse(
+            quote!(for invoke_impl_i in invoke_impl_iter {
+                #match_statement
+            })
+            .into(),
+        )
+        .unwrap()
+    };
+
+    // Add loop to block
+    invoke_block.stmts.push(Stmt::Expr(Expr::ForLoop(loopexpr)));
+    if on_invalid == InvalidIndexMode::Error {
+        invoke_block.stmts.push(Stmt::Expr(
+            syn::parse(quote!(::core::result::Result::Ok(())).into()).unwrap(),
+        ));
+    }
+
+    invoke_block
+}
+
+/// Helper function to generate the default match arm of invoke functions dispatching on usize
+/// indices, which handles indices out of range as on_invalid says
+fn invalid_index_arm(on_invalid: InvalidIndexMode) -> Arm {
+    match on_invalid {
+        InvalidIndexMode::Skip => syn::parse(quote!(_ => {}).into()).unwrap(),
+        InvalidIndexMode::Panic => {
+            syn::parse(quote!(_ => panic!("Iter contains invalid function index!")).into()).unwrap()
+        }
+        InvalidIndexMode::Error => syn::parse(
+            quote!(invoke_impl_invalid => return ::core::result::Result::Err(invoke_impl_invalid),)
+                .into(),
+        )
+        .unwrap(),
+    }
+}
+
+/// Generates bodies for invoke_all_enum and invoke_all_enumerated
+#[allow(clippy::too_many_arguments)]
+fn invoke_all_enum_block(
+    is_method: bool,
+    specification_type: SpecificationType,
+    output_type: &ReturnType,
+    methods: &Vec<&ImplItemMethod>,
+    closure_ident: &Ident,
+    consumer_mode: ConsumerMode,
+    enum_name: &Ident,
+    variant_idents: &[Ident],
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    // Set up body block for the invoke  method:
+    let mut invoke_block = Block {
+        brace_token: Default::default(),
+        stmts: vec![],
+    };
+
+    for (index, (enum_ident, &method)) in variant_idents.iter().zip(methods.iter()).enumerate() {
+        let cfgs = cfg_attrs(method);
+
+        // Get inner call
+        let inner_call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+
+        // Convert/merge to outer call
+        let outer_call: ExprCall = if output_type != &generate_trailing_return_type()
+            && output_type != &ReturnType::Default
+        {
+            // Functions have return type, so the invoke function accepts a closure with returntype
+            // Insert previous call into a call of consumer:
+            let consumed_call = consumed_expr(consumer_mode, &inner_call);
+            match specification_type {
+                SpecificationType::Enum => syn::parse(
+                    quote!(#closure_ident(#enum_name::#enum_ident, #consumed_call)).into(),
+                )
+                .unwrap(),
+                SpecificationType::Enumerated => {
+                    syn::parse(quote!(#closure_ident(#index, #consumed_call)).into()).unwrap()
+                }
+            }
+        } else {
+            // Closure takes iteration type; so call inner first and then call closure:
+            invoke_block
+                .stmts
+                .push(syn::parse(quote!(#(#cfgs)* #inner_call;).into()).unwrap());
+            match specification_type {
+                SpecificationType::Enum => {
+                    syn::parse(quote!(#closure_ident(#enum_name::#enum_ident)).into()).unwrap()
+                }
+                SpecificationType::Enumerated => {
+                    syn::parse(quote!(#closure_ident(#index)).into()).unwrap()
+                }
+            }
+        };
+
+        // Add outer call to block
+        invoke_block
+            .stmts
+            .push(syn::parse(quote!(#(#cfgs)* #outer_call;).into()).unwrap());
+    }
+
+    invoke_block
+}
+
+/// Generates bodies for invoke_enum and invoke_enumerated
+#[allow(clippy::too_many_arguments)]
+fn invoke_enum_block(
+    is_method: bool,
+    specification_type: SpecificationType,
+    output_type: &ReturnType,
+    methods: &Vec<&ImplItemMethod>,
+    closure_ident: &Ident,
+    consumer_mode: ConsumerMode,
+    enum_name: &Ident,
+    variant_idents: &[Ident],
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+    on_invalid: InvalidIndexMode,
+) -> Block {
+    // Set up body block for the invoke  method:
+    let mut invoke_block = Block {
+        brace_token: Default::default(),
+        stmts: vec![],
+    };
+
+    // Set up inner match statement
     let mut match_statement: ExprMatch = syn::parse(quote!(match invoke_impl_i {}).into()).unwrap();
 
-    // Iterate over methods, generating match arms:
-    for (index, (enum_ident, &method)) in
-        identifiers.into_iter().zip(methods.into_iter()).enumerate()
-    {
-        // Get inner call
-        let inner_call =
-            get_inner_call_expr(is_method, method, struct_ident, generic_params, param_ids);
+    // Iterate over methods, generating match arms:
+    for (index, (enum_ident, &method)) in variant_idents.iter().zip(methods.iter()).enumerate() {
+        // Get inner call
+        let inner_call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+
+        // Convert/merge to outer call
+        let outer_call: Expr = if output_type != &generate_trailing_return_type()
+            && output_type != &ReturnType::Default
+        {
+            // Functions have return type, so the invoke function accepts a closure
+            // Insert previous call into a call of consumer with appropriate specification type:
+            let consumed_call = consumed_expr(consumer_mode, &inner_call);
+            match specification_type {
+                SpecificationType::Enum => syn::parse(
+                    quote!({#closure_ident(#enum_name::#enum_ident, #consumed_call);}).into(),
+                )
+                .unwrap(),
+                SpecificationType::Enumerated => {
+                    syn::parse(quote!({#closure_ident(#index, #consumed_call);}).into()).unwrap()
+                }
+            }
+        } else {
+            // Need to pass in only specifier to closure, so call inner_call first and then closure
+            match specification_type {
+                SpecificationType::Enum => syn::parse(
+                    quote!({
+                        #inner_call;
+                        #closure_ident(#enum_name::#enum_ident);
+                    })
+                    .into(),
+                )
+                .unwrap(),
+                SpecificationType::Enumerated => syn::parse(
+                    quote!({
+                        #inner_call;
+                        #closure_ident(#index);
+                    })
+                    .into(),
+                )
+                .unwrap(),
+            }
+        };
+
+        // Parse to match arm
+        let cfgs = cfg_attrs(method);
+        match specification_type {
+            SpecificationType::Enum => {
+                match_statement.arms.push(
+                    syn::parse(quote!(#(#cfgs)* #enum_name::#enum_ident => #outer_call,).into())
+                        .unwrap(),
+                );
+            }
+            SpecificationType::Enumerated => {
+                match_statement
+                    .arms
+                    .push(syn::parse(quote!(#(#cfgs)* #index => #outer_call,).into()).unwrap());
+            }
+        }
+    }
+
+    // Add default case to match statement if enumerated by usize
+    match specification_type {
+        SpecificationType::Enum => {}
+        SpecificationType::Enumerated => {
+            match_statement.arms.push(invalid_index_arm(on_invalid));
+        }
+    }
+
+    // Wrap match in loop
+    let loopexpr: ExprForLoop = syn::parse(
+        quote!(for invoke_impl_i in invoke_impl_iter {
+            #match_statement
+        })
+        .into(),
+    )
+    .unwrap();
+
+    // Add loop to block
+    invoke_block.stmts.push(Stmt::Expr(Expr::ForLoop(loopexpr)));
+    if let (SpecificationType::Enumerated, InvalidIndexMode::Error) =
+        (specification_type, on_invalid)
+    {
+        invoke_block.stmts.push(Stmt::Expr(
+            syn::parse(quote!(::core::result::Result::Ok(())).into()).unwrap(),
+        ));
+    }
+
+    invoke_block
+}
+
+/// Generates a body block for the invoke_by_name function, which parses every name into the enum
+/// before dispatching them like invoke_enum, so that an unknown name, which the block evaluates to
+/// as the Err, is reported before any function is invoked
+#[allow(clippy::too_many_arguments)]
+fn invoke_by_name_block(
+    is_method: bool,
+    output_type: &ReturnType,
+    methods: &Vec<&ImplItemMethod>,
+    closure_ident: &Ident,
+    consumer_mode: ConsumerMode,
+    enum_name: &Ident,
+    variant_idents: &[Ident],
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    let dispatch = invoke_enum_block(
+        is_method,
+        SpecificationType::Enum,
+        output_type,
+        methods,
+        closure_ident,
+        consumer_mode,
+        enum_name,
+        variant_idents,
+        generic_params,
+        param_ids,
+        InvalidIndexMode::Panic,
+    );
+    syn::parse(
+        quote!({
+            let invoke_impl_iter = invoke_impl_names
+                .map(|invoke_impl_name| {
+                    <#enum_name as ::core::convert::TryFrom<&str>>::try_from(invoke_impl_name)
+                        .map_err(|_| ::std::string::String::from(invoke_impl_name))
+                })
+                .collect::<::core::result::Result<::std::vec::Vec<#enum_name>, _>>()?;
+            #dispatch
+            ::core::result::Result::Ok(())
+        })
+        .into(),
+    )
+    .unwrap()
+}
+
+/// Generates a body block for the invoke_all_partition function, which invokes every function in
+/// impl block order and evaluates to a HashMap from the keys invoke_impl_classify gives the results
+/// to the results with that key, paired with the indices of their functions
+fn invoke_all_partition_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    // Generate a statement filing the result of each function under its key
+    let inserts = methods
+        .iter()
+        .enumerate()
+        .map(|(index, &method)| {
+            let inner_call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+            let cfgs = cfg_attrs(method);
+            quote!(
+                #(#cfgs)* {
+                    let invoke_impl_result = #inner_call;
+                    invoke_impl_partition
+                        .entry(invoke_impl_classify(&invoke_impl_result))
+                        .or_insert_with(::std::vec::Vec::new)
+                        .push((#index, invoke_impl_result));
+                }
+            )
+        })
+        .collect::<Vec<_>>();
+
+    syn::parse(
+        quote!({
+            let mut invoke_impl_partition = ::std::collections::HashMap::new();
+            #(#inserts)*
+            invoke_impl_partition
+        })
+        .into(),
+    )
+    .unwrap()
+}
+
+/// Generates a body block for the invoke_all_extend function, which extends invoke_impl_target with
+/// the result of every function in impl block order
+fn invoke_all_extend_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    let mut invoke_block = Block {
+        brace_token: Default::default(),
+        stmts: vec![],
+    };
+    for &method in methods {
+        let inner_call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+        let cfgs = cfg_attrs(method);
+        invoke_block.stmts.push(
+            syn::parse(
+                quote!(#(#cfgs)* ::core::iter::Extend::extend(
+                    invoke_impl_target,
+                    ::core::iter::once(#inner_call),
+                );)
+                .into(),
+            )
+            .unwrap(),
+        );
+    }
+    invoke_block
+}
+
+/// Generates a body block for the invoke_all_into function, which writes the result of every
+/// function into the slot of invoke_impl_out at its index, in impl block order
+fn invoke_all_into_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    let mut invoke_block = Block {
+        brace_token: Default::default(),
+        stmts: vec![],
+    };
+    for (index, &method) in methods.iter().enumerate() {
+        let inner_call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+        let cfgs = cfg_attrs(method);
+        invoke_block.stmts.push(
+            syn::parse(quote!(#(#cfgs)* { invoke_impl_out[#index] = #inner_call; }).into())
+                .unwrap(),
+        );
+    }
+    invoke_block
+}
+
+/// Generates a body block for the invoke_all_fold function, which folds the result of every
+/// function in impl block order into the accumulator, starting from invoke_impl_init, and evaluates
+/// to the accumulator. If the enum and its variants are provided, the variant of each function is
+/// passed to invoke_impl_fold along with its result (as for invoke_all_enum_fold).
+fn invoke_all_fold_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    variants: Option<(&Ident, &[Ident])>,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    let folds = methods
+        .iter()
+        .enumerate()
+        .map(|(index, &method)| {
+            let inner_call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+            let cfgs = cfg_attrs(method);
+            let variant = variants.map(|(enum_name, variant_idents)| {
+                let enum_ident = &variant_idents[index];
+                quote!(#enum_name::#enum_ident,)
+            });
+            quote!(#(#cfgs)* {
+                invoke_impl_acc = invoke_impl_fold(invoke_impl_acc, #variant #inner_call);
+            })
+        })
+        .collect::<Vec<_>>();
+
+    syn::parse(
+        quote!({
+            let mut invoke_impl_acc = invoke_impl_init;
+            #(#folds)*
+            invoke_impl_acc
+        })
+        .into(),
+    )
+    .unwrap()
+}
+
+/// Generates a body block for the invoke_all_with function, which passes the accumulator along
+/// with the result of every function in impl block order to the closure named closure_ident
+fn invoke_all_with_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    closure_ident: &Ident,
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    let calls = methods
+        .iter()
+        .map(|&method| {
+            let inner_call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+            let cfgs = cfg_attrs(method);
+            quote!(#(#cfgs)* {
+                #closure_ident(&mut *invoke_impl_acc, #inner_call);
+            })
+        })
+        .collect::<Vec<_>>();
+
+    syn::parse(quote!({ #(#calls)* }).into()).unwrap()
+}
+
+/// Generates a body block for the invoke_enum_collect function, which invokes the functions
+/// designated by the variants of invoke_impl_iter in its order and evaluates to a Vec of the
+/// variants paired with the results of their functions
+fn invoke_enum_collect_block(
+    is_method: bool,
+    methods: &Vec<&ImplItemMethod>,
+    enum_name: &Ident,
+    variant_idents: &[Ident],
+    generic_params: &Vec<Ident>,
+    param_ids: &Vec<Expr>,
+) -> Block {
+    // Generate a match arm pushing the result of each function, along with its variant
+    let arms = methods
+        .iter()
+        .zip(variant_idents)
+        .map(|(&method, enum_ident)| {
+            let inner_call = get_inner_call_expr(is_method, method, generic_params, param_ids);
+            let cfgs = cfg_attrs(method);
+            quote!(
+                #(#cfgs)* #enum_name::#enum_ident => {
+                    invoke_impl_results.push((#enum_name::#enum_ident, #inner_call))
+                }
+            )
+        })
+        .collect::<Vec<_>>();
+
+    syn::parse(
+        quote!({
+            let mut invoke_impl_results = ::std::vec::Vec::new();
+            for invoke_impl_i in invoke_impl_iter {
+                match invoke_impl_i {
+                    #(#arms)*
+                }
+            }
+            invoke_impl_results
+        })
+        .into(),
+    )
+    .unwrap()
+}
+
+/// Helper function to replace the parameters of an invoke signature that would be forwarded with a
+/// factory closure, which builds the arguments of a function given the index of that function.
+/// The factory returns a tuple of the arguments, or the lone argument itself if there is only one.
+/// Returns a pattern binding the output of the factory and the expressions forwarding the bound
+/// arguments.
+fn replace_params_with_factory(invoke_sig: &mut Signature) -> (Pat, Vec<Expr>) {
+    let mut receiver = None;
+    let mut arg_idents = vec![];
+    let mut arg_types = vec![];
+    for fnarg in &invoke_sig.inputs {
+        match fnarg {
+            FnArg::Receiver(_) => receiver = Some(fnarg.clone()),
+            Typed(pattype) => {
+                if let Pat::Ident(ref patident) = *pattype.pat {
+                    arg_idents.push(patident.ident.clone());
+                    arg_types.push(*pattype.ty.clone());
+                }
+            }
+        }
+    }
+
+    let (binding, args_type) = if arg_idents.len() == 1 {
+        (quote!(#(#arg_idents)*), quote!(#(#arg_types)*))
+    } else {
+        (quote!((#(#arg_idents),*)), quote!((#(#arg_types),*)))
+    };
+
+    invoke_sig.inputs = receiver.into_iter().collect();
+    invoke_sig
+        .inputs
+        .push(syn::parse(quote!(mut factory: impl FnMut(usize) -> #args_type).into()).unwrap());
+
+    let forwarded = arg_idents
+        .into_iter()
+        .map(|id| Expr::Path(syn::parse(quote!(#id).into()).unwrap()))
+        .collect();
+    (syn::parse(binding.into()).unwrap(), forwarded)
+}
+
+/// Creates a thin wrapper function run_{method}, with the signature of method, that just calls
+/// method. As there is only the one call, parameters marked for cloning are moved rather than
+/// cloned, as is self taken by value.
+fn create_run_function(method: &ImplItemMethod) -> ImplItem {
+    let mut run_sig = Signature {
+        ident: format_ident!("run_{}", method.sig.ident),
+        ..method.sig.clone()
+    };
 
-        // Convert/merge to outer call
-        let outer_call: Expr = if output_type != &generate_trailing_return_type()
-            && output_type != &ReturnType::Default
-        {
-            // Functions have return type, so the invoke function accepts a closure
-            // Insert previous call into a call of consumer with appropriate specification type:
-            match specification_type {
-                SpecificationType::Enum => syn::parse(
-                    quote!({#closure_ident(#enum_name::#enum_ident, #inner_call);}).into(),
-                )
-                .unwrap(),
-                SpecificationType::Enumerated => {
-                    syn::parse(quote!({#closure_ident(#index, #inner_call);}).into()).unwrap()
-                }
+    // The wrapper only moves self on, so it doesn't need mut self
+    if let Some(FnArg::Receiver(receiver)) = run_sig.inputs.first_mut() {
+        if receiver.reference.is_none() {
+            receiver.mutability = None;
+        }
+    }
+    bind_pattern_params(&mut run_sig);
+
+    let is_method = matches!(run_sig.inputs.first(), Some(FnArg::Receiver(_)));
+    let generic_params = forwarded_generic_params(&run_sig);
+    let param_ids = forwarded_param_idents(&run_sig)
+        .into_iter()
+        .map(|id| Expr::Path(syn::parse(quote!(#id).into()).unwrap()))
+        .collect::<Vec<_>>();
+    let call = get_inner_call_expr(is_method, method, &generic_params, &param_ids);
+    let call = if run_sig.asyncness.is_some() {
+        quote!(#call.await)
+    } else {
+        quote!(#call)
+    };
+    let call = if run_sig.unsafety.is_some() {
+        quote!(unsafe { #call })
+    } else {
+        call
+    };
+
+    ImplItem::Method(ImplItemMethod {
+        sig: run_sig,
+        block: syn::parse(quote!({ #call }).into()).unwrap(),
+        ..method.clone()
+    })
+}
+
+/// Helper function to bind the parameters of sig destructured by a pattern (such as a tuple, a
+/// reference or _) to the identifiers they are forwarded by instead, as a pattern can't be
+/// forwarded
+fn bind_pattern_params(sig: &mut Signature) {
+    let typed = sig.inputs.iter_mut().filter_map(|fnarg| match fnarg {
+        Typed(pattype) => Some(pattype),
+        FnArg::Receiver(_) => None,
+    });
+    for (index, pattype) in typed.enumerate() {
+        if !matches!(*pattype.pat, Pat::Ident(_)) {
+            let id = forwarded_param_ident(index, pattype);
+            *pattype.pat = syn::parse(quote!(#id).into()).unwrap();
+        }
+    }
+}
+
+/// Helper function to get the generic parameters of sig, in declaration order, that need to be
+/// forwarded in the turbofish. Lifetime parameters are deliberately left out: the generated
+/// function keeps the same lifetime parameters in its own signature, so they are inferred at each
+/// call, and specifying them explicitly is an error whenever any of them are late bound.
+fn forwarded_generic_params(sig: &Signature) -> Vec<Ident> {
+    sig.generics
+        .params
+        .iter()
+        .cloned()
+        .filter_map(|gp| match gp {
+            GenericParam::Type(tp) => Some(tp.ident),
+            GenericParam::Const(cp) => Some(cp.ident),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Helper function to get the identifiers of the parameters that would be forwarded, in
+/// declaration order
+fn forwarded_param_idents(sig: &Signature) -> Vec<Ident> {
+    sig.inputs
+        .iter()
+        .filter_map(|fnarg| match fnarg {
+            Typed(pattype) => Some(pattype),
+            FnArg::Receiver(_) => None,
+        })
+        .enumerate()
+        .map(|(index, pattype)| forwarded_param_ident(index, pattype))
+        .collect()
+}
+
+/// Helper function to get the identifier a parameter is forwarded by: its own, if its pattern is a
+/// plain identifier, or arg{index} for any other pattern (such as a tuple, a reference or _), with
+/// index its position among the parameters after the receiver, as counted by the clone argument
+fn forwarded_param_ident(index: usize, pattype: &PatType) -> Ident {
+    match *pattype.pat {
+        Pat::Ident(ref patident) => patident.ident.clone(),
+        _ => format_ident!("arg{}", index),
+    }
+}
+
+/// Safety function to check that functions like base_method can be invoked in parallel by
+/// invoke_all_par. This needs the rayon feature, and synchronous functions with a return type.
+/// Every spawned function needs its own copies of the arguments, so all the parameters need to be
+/// marked for cloning, and the receiver (if any) has to be shareable, so it can't be &mut self.
+/// Generic type parameters are ruled out, as the functions don't require them to be Send. Returns
+/// an error spanning the signature of base_method, naming what rules it out, if not true.
+fn validate_parallel(
+    base_method: &ImplItemMethod,
+    clone: &Option<HashSet<usize>>,
+) -> Result<(), syn::Error> {
+    let sig = &base_method.sig;
+    let all_cloned = sig
+        .inputs
+        .iter()
+        .filter(|fnarg| matches!(fnarg, Typed(_)))
+        .enumerate()
+        .all(|(index, _)| clone.as_ref().is_some_and(|hs| hs.contains(&index)));
+    let problem = if !cfg!(feature = "rayon") {
+        "the parallel argument requires the rayon feature of invoke_impl"
+    } else if sig.asyncness.is_some() {
+        "invoke_all_par can't run async functions"
+    } else if !has_return_type(&sig.output) {
+        "invoke_all_par needs functions with a return type to collect"
+    } else if has_value_receiver(sig) {
+        "invoke_all_par can't move self into every thread"
+    } else if has_mut_receiver(sig) {
+        "invoke_all_par can't share &mut self between threads"
+    } else if has_type_generics(sig) {
+        "invoke_all_par can't run functions with generic type parameters"
+    } else if !all_cloned {
+        "invoke_all_par gives every function its own copies of the arguments, so every parameter \
+        must be marked with the clone argument"
+    } else {
+        return Ok(());
+    };
+    Err(syn::Error::new_spanned(sig, problem))
+}
+
+/// Safety function to check that every clone index names a parameter of functions like base_method.
+/// The indices count the parameters after the receiver, if any, so 0 is always the first parameter
+/// that isn't self (which only clone_self clones). Returns an error spanning the first offending
+/// index if not true.
+fn validate_clone_indices(
+    base_method: &ImplItemMethod,
+    clones: &Option<Vec<LitInt>>,
+) -> Result<(), syn::Error> {
+    let count = base_method
+        .sig
+        .inputs
+        .iter()
+        .filter(|fnarg| matches!(fnarg, Typed(_)))
+        .count();
+    for lit in clones.iter().flatten() {
+        let index = lit.base10_parse::<usize>()?;
+        if index >= count {
+            return Err(syn::Error::new_spanned(
+                lit,
+                format!(
+                    "clone index {} is out of range, as indices must be less than {}, the number \
+                    of parameters not counting self",
+                    index, count
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Safety function to check that the clone_self argument is given exactly when the functions take
+/// self by value, since such methods can only be invoked one after another on clones of self.
+/// Returns an error spanning the receiver or the signature if not true.
+fn validate_clone_self(base_method: &ImplItemMethod, clone_self: bool) -> Result<(), syn::Error> {
+    let sig = &base_method.sig;
+    match sig.receiver() {
+        Some(receiver) if has_value_receiver(sig) && !clone_self => Err(syn::Error::new_spanned(
+            receiver,
+            "methods taking self by value need the clone_self argument, which passes each of them \
+            a clone of self",
+        )),
+        _ if clone_self && !has_value_receiver(sig) => Err(syn::Error::new_spanned(
+            sig,
+            "clone_self only applies to methods taking self by value",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Safety function to check that the functions don't take self when the free_fns argument is given,
+/// as free functions have no self to pass on. Returns an error spanning the receiver if not true.
+fn validate_free_fns(base_method: &ImplItemMethod, free_fns: bool) -> Result<(), syn::Error> {
+    match base_method.sig.receiver() {
+        Some(receiver) if free_fns => Err(syn::Error::new_spanned(
+            receiver,
+            "free_fns only applies to associated functions, as free functions have no self to \
+            call methods on",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Safety function to check that the functions take &self when the invoker argument is given, as
+/// the invoker only holds a shared reference to pass on. Returns an error spanning the receiver
+/// (or the signature, if there is none) if not true.
+fn validate_invoker(
+    base_method: &ImplItemMethod,
+    invoker: &Option<Ident>,
+) -> Result<(), syn::Error> {
+    match base_method.sig.receiver() {
+        _ if invoker.is_none() => Ok(()),
+        Some(FnArg::Receiver(Receiver {
+            reference: Some(_),
+            mutability: None,
+            ..
+        })) => Ok(()),
+        Some(receiver) => Err(syn::Error::new_spanned(
+            receiver,
+            "invoker only applies to methods taking &self, as the invoker holds a shared \
+            reference to call them on",
+        )),
+        None => Err(syn::Error::new_spanned(
+            &base_method.sig,
+            "invoker only applies to methods taking &self, as associated functions have no self \
+            for the invoker to hold",
+        )),
+    }
+}
+
+/// Safety function to check that functions like base_method aren't private when pub items wrapping
+/// them are generated: the invoke functions made pub by vis("pub"), the module of free_fns or the
+/// invoker struct, which are pub unless vis says otherwise. Without those, the invoke functions are
+/// only as visible as the functions they call. The functions of trait impls are as visible as their
+/// trait, which can't be seen from the impl, so the inherent impl generated for them isn't checked.
+/// Returns an error spanning the name of base_method if not true.
+fn validate_exported_surface(
+    input: &ItemImpl,
+    base_method: &ImplItemMethod,
+    vis: &Option<Visibility>,
+    free_fns: bool,
+    invoker: &Option<Ident>,
+) -> Result<(), syn::Error> {
+    if input.trait_.is_some() {
+        return Ok(());
+    }
+    let exported_by = match vis {
+        Some(Visibility::Public(_)) => "vis(\"pub\") makes the invoke functions calling it pub",
+        Some(_) => return Ok(()),
+        None if free_fns => "free_fns generates pub free functions calling it",
+        None if invoker.is_some() => "invoker generates a pub struct calling it",
+        None => return Ok(()),
+    };
+    match base_method.vis {
+        Visibility::Inherited => Err(syn::Error::new_spanned(
+            &base_method.sig.ident,
+            format!(
+                "`{}` is private, but {}; make the functions pub, or pass a narrower visibility \
+                 such as vis(\"pub(crate)\")",
+                base_method.sig.ident, exported_by
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Safety function to check that the parameters of functions like base_method can be taken from the
+/// fields of a struct by invoke_all_from, which delegates to invoke_all. As the fields are looked
+/// up by the names of the parameters, every parameter has to be named by a plain identifier, and as
+/// the struct is borrowed shared, none of them can be a mutable reference. Generic functions are
+/// ruled out as well, since the fields couldn't determine their type parameters, as are async
+/// functions, which have no invoke_all to delegate to. Returns an error spanning the offending part
+/// of the signature if not true.
+fn validate_args_struct(base_method: &ImplItemMethod) -> Result<(), syn::Error> {
+    let sig = &base_method.sig;
+    if let Some(asyncness) = sig.asyncness {
+        return Err(syn::Error::new_spanned(
+            asyncness,
+            "args_struct isn't supported for async functions, as invoke_all_from delegates to \
+            invoke_all",
+        ));
+    }
+    if !sig.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &sig.generics,
+            "args_struct isn't supported for generic functions, as the fields of the struct can't \
+            determine their generic parameters",
+        ));
+    }
+    for fnarg in &sig.inputs {
+        if let Typed(pattype) = fnarg {
+            if !matches!(*pattype.pat, Pat::Ident(_)) {
+                return Err(syn::Error::new_spanned(
+                    &pattype.pat,
+                    "args_struct looks fields up by the names of the parameters, so they can't be \
+                    destructured by a pattern",
+                ));
             }
-        } else {
-            // Need to pass in only specifier to closure, so call inner_call first and then closure
-            match specification_type {
-                SpecificationType::Enum => syn::parse(
-                    quote!({
-                        #inner_call;
-                        #closure_ident(#enum_name::#enum_ident);
-                    })
-                    .into(),
-                )
-                .unwrap(),
-                SpecificationType::Enumerated => syn::parse(
-                    quote!({
-                        #inner_call;
-                        #closure_ident(#index);
-                    })
-                    .into(),
-                )
-                .unwrap(),
+            if let Type::Reference(TypeReference {
+                mutability: Some(_),
+                ..
+            }) = *pattype.ty
+            {
+                return Err(syn::Error::new_spanned(
+                    &pattype.ty,
+                    "args_struct passes the fields of a shared reference, so parameters can't be \
+                    mutable references",
+                ));
             }
-        };
+        }
+    }
+    Ok(())
+}
 
-        // Parse to match arm
-        match specification_type {
-            SpecificationType::Enum => {
-                match_statement.arms.push(
-                    syn::parse(quote!(#enum_name::#enum_ident => #outer_call,).into()).unwrap(),
-                );
+/// Creates invoke_all_from, which takes args: &args_struct in place of the parameters of
+/// invoke_all and calls invoke_all with its fields, each field named like the parameter it is
+/// passed as. Fields are passed by reference to parameters taking a reference, and cloned for all
+/// other parameters. Receiver and consumer are passed on as they are.
+fn create_invoke_all_from_function(
+    invoke_all: &ImplItem,
+    base_method: &ImplItemMethod,
+    args_struct: &syn::Path,
+    name: &Option<String>,
+    prefix: &Option<String>,
+    verb: &Option<String>,
+) -> ImplItem {
+    let invoke_all = match invoke_all {
+        ImplItem::Method(method) => method,
+        _ => unreachable!("invoke functions are methods"),
+    };
+    let invoke_all_ident = &invoke_all.sig.ident;
+
+    // The fields passed in place of the parameters of the functions
+    let fields = base_method
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|fnarg| match fnarg {
+            Typed(pattype) => {
+                let field = forwarded_param_ident(0, pattype);
+                Some(match *pattype.ty {
+                    Type::Reference(_) => quote!(&args.#field),
+                    _ => quote!(::core::clone::Clone::clone(&args.#field)),
+                })
             }
-            SpecificationType::Enumerated => {
-                match_statement
-                    .arms
-                    .push(syn::parse(quote!(#index => #outer_call,).into()).unwrap());
+            FnArg::Receiver(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Whatever invoke_all takes after those parameters (the consumer, if any) is passed on
+    let receiver = invoke_all.sig.receiver().cloned();
+    let mut rest = invoke_all
+        .sig
+        .inputs
+        .iter()
+        .filter(|fnarg| matches!(fnarg, Typed(_)))
+        .skip(fields.len())
+        .cloned()
+        .collect::<Vec<_>>();
+    for fnarg in rest.iter_mut() {
+        if let Typed(pattype) = fnarg {
+            if let Pat::Ident(patident) = &mut *pattype.pat {
+                patident.mutability = None;
             }
         }
     }
+    let rest_ids = forwarded_param_idents(&Signature {
+        inputs: rest.iter().cloned().collect(),
+        ..invoke_all.sig.clone()
+    });
 
-    // Add default case to match statement if enumerated by usize
-    match specification_type {
-        SpecificationType::Enum => {}
-        SpecificationType::Enumerated => {
-            match_statement.arms.push(
-                syn::parse(quote!(_ => panic!("Iter contains invalid function index!")).into())
-                    .unwrap(),
-            );
+    let from_sig = Signature {
+        ident: generate_invoke_all_from_name(name, prefix, verb),
+        inputs: receiver
+            .into_iter()
+            .chain(std::iter::once(
+                syn::parse(quote!(args: &#args_struct).into()).unwrap(),
+            ))
+            .chain(rest)
+            .collect(),
+        ..invoke_all.sig.clone()
+    };
+
+    let call = if from_sig.receiver().is_some() {
+        quote!(self.#invoke_all_ident(#(#fields,)* #(#rest_ids),*))
+    } else {
+        quote!(Self::#invoke_all_ident(#(#fields,)* #(#rest_ids),*))
+    };
+    let call = if from_sig.unsafety.is_some() {
+        quote!(unsafe { #call })
+    } else {
+        call
+    };
+
+    ImplItem::Method(ImplItemMethod {
+        sig: from_sig,
+        block: syn::parse(quote!({ #call }).into()).unwrap(),
+        ..invoke_all.clone()
+    })
+}
+
+/// Helper function to check whether functions with signature sig take self by value
+fn has_value_receiver(sig: &Signature) -> bool {
+    sig.inputs.iter().any(|fnarg| match fnarg {
+        FnArg::Receiver(receiver) => receiver.reference.is_none(),
+        Typed(_) => false,
+    })
+}
+
+/// Helper struct to rewrite the method calls on self of a block into calls on clones of self. If
+/// keep is given, the call with that index (in the order the calls are visited) is left alone.
+/// Counts the calls visited either way.
+struct SelfCloner {
+    keep: Option<usize>,
+    visited: usize,
+}
+
+impl VisitMut for SelfCloner {
+    fn visit_expr_method_call_mut(&mut self, call: &mut ExprMethodCall) {
+        visit_mut::visit_expr_method_call_mut(self, call);
+        if let Expr::Path(path) = &*call.receiver {
+            if path.path.is_ident("self") {
+                if self.keep != Some(self.visited) {
+                    call.receiver = syn::parse(quote!(self.clone()).into()).unwrap();
+                }
+                self.visited += 1;
+            }
+        }
+    }
+}
+
+/// Helper function to make the calls of the functions in an invoke block call them on clones of
+/// self, for methods taking self by value. If move_last is true, the last call moves self instead.
+fn clone_self_in_calls(block: &mut Block, move_last: bool) {
+    let keep = if move_last {
+        // Count the calls on a copy first, to find the last one
+        let mut counter = SelfCloner {
+            keep: None,
+            visited: 0,
+        };
+        counter.visit_block_mut(&mut block.clone());
+        counter.visited.checked_sub(1)
+    } else {
+        None
+    };
+    SelfCloner { keep, visited: 0 }.visit_block_mut(block);
+}
+
+/// Helper function to check whether functions with signature sig take &mut self
+fn has_mut_receiver(sig: &Signature) -> bool {
+    sig.inputs.iter().any(|fnarg| match fnarg {
+        FnArg::Receiver(receiver) => receiver.mutability.is_some(),
+        Typed(_) => false,
+    })
+}
+
+/// Helper function to check whether functions with signature sig have generic type parameters
+fn has_type_generics(sig: &Signature) -> bool {
+    sig.generics
+        .params
+        .iter()
+        .any(|gp| matches!(gp, GenericParam::Type(_)))
+}
+
+/// Helper function to check whether any of the parameters that would be forwarded borrow
+/// something, i.e. their types are references or otherwise mention a lifetime. Such arguments
+/// can't be returned from a factory closure without spelling out lifetime bounds the functions
+/// themselves don't declare, so invoke_subset_lazy isn't generated for them.
+fn params_mention_lifetimes(sig: &Signature) -> bool {
+    let mut visitor = MentionsLifetime(false);
+    for fnarg in &sig.inputs {
+        if let Typed(pattype) = fnarg {
+            visitor.visit_type(&pattype.ty);
         }
     }
+    visitor.0
+}
 
-    // Wrap match in loop
-    let loopexpr: ExprForLoop = syn::parse(
-        quote!(for invoke_impl_i in invoke_impl_iter {
-            #match_statement
+/// Generates a warning for every reference to an invoke function (named in invoke_names) in the
+/// body of an invoked function, as calling it recurses without end unless the call is guarded.
+/// Stable proc macros can't emit warnings, so each one is a statement using a #[deprecated] unit
+/// struct, spanning the reference, paired with the function it is to be prepended to the body of;
+/// that way, #[allow(deprecated)] on the function silences it.
+fn create_recursion_warnings(
+    methods: &[&ImplItemMethod],
+    invoke_names: &[Ident],
+) -> Vec<(Ident, Stmt)> {
+    methods
+        .iter()
+        .flat_map(|&method| {
+            let mut finder = NameFinder {
+                names: invoke_names,
+                found: vec![],
+            };
+            finder.visit_block(&method.block);
+            finder.found.into_iter().map(move |reference| {
+                let note = format!(
+                    "`{}` refers to `{}`, which invokes `{}` itself, recursing without end unless \
+                     the call is guarded",
+                    method.sig.ident, reference, method.sig.ident
+                );
+                let warning = quote_spanned!(reference.span()=> {
+                    #[deprecated(note = #note)]
+                    #[allow(non_camel_case_types)]
+                    struct invoke_impl_recursion;
+                    let _ = invoke_impl_recursion;
+                });
+                (
+                    method.sig.ident.clone(),
+                    syn::parse(warning.into()).unwrap(),
+                )
+            })
         })
-        .into(),
-    )
-    .unwrap();
+        .collect()
+}
 
-    // Add loop to block
-    invoke_block.stmts.push(Stmt::Expr(Expr::ForLoop(loopexpr)));
+/// Visitor collecting the identifiers among names that a block refers to, as the last segment of a
+/// path (such as Self::invoke_all) or the method of a method call
+struct NameFinder<'a> {
+    names: &'a [Ident],
+    found: Vec<Ident>,
+}
 
-    invoke_block
+impl<'ast> Visit<'ast> for NameFinder<'_> {
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        if self.names.contains(&call.method) {
+            self.found.push(call.method.clone());
+        }
+        visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_expr_path(&mut self, path: &'ast syn::ExprPath) {
+        // A lone identifier is a local or a free function, not an invoke function
+        if let (true, Some(segment)) = (path.path.segments.len() > 1, path.path.segments.last()) {
+            if self.names.contains(&segment.ident) {
+                self.found.push(segment.ident.clone());
+            }
+        }
+        visit::visit_expr_path(self, path);
+    }
+}
+
+/// Visitor recording whether a type contains a reference or a lifetime
+struct MentionsLifetime(bool);
+
+impl<'ast> Visit<'ast> for MentionsLifetime {
+    fn visit_type_reference(&mut self, _: &'ast TypeReference) {
+        self.0 = true;
+    }
+
+    fn visit_lifetime(&mut self, _: &'ast Lifetime) {
+        self.0 = true;
+    }
 }
 
-/// Helper function to generate inner function calls
+/// Helper function to generate inner function calls. Associated functions are called through Self,
+/// which keeps the generic arguments of the self type, e.g. Container<T> of impl<T> Container<T>.
+/// With the log feature enabled, the call is preceded by a log::debug! naming the function.
 fn get_inner_call_expr(
     is_method: bool,
     method: &ImplItemMethod,
-    struct_ident: &Ident,
     generic_params: &Vec<Ident>,
     param_ids: &Vec<Expr>,
 ) -> Expr {
-    // Generate inner call
+    // Generate inner call, with a turbofish only if there are generic parameters to forward
     let method_name = method.sig.ident.clone();
-    if is_method {
+    let turbofish = if generic_params.is_empty() {
+        None
+    } else {
+        Some(quote!(::<#(#generic_params),*>))
+    };
+    let call = if is_method {
         Expr::MethodCall(
-            syn::parse(quote!(self.#method_name::<#(#generic_params),*>(#(#param_ids),*)).into())
-                .unwrap(),
+            syn::parse(quote!(self.#method_name #turbofish(#(#param_ids),*)).into()).unwrap(),
         )
     } else {
         Expr::Call(
-            syn::parse(
-                quote!(#struct_ident::#method_name::<#(#generic_params),*>(#(#param_ids),*)).into(),
-            )
-            .unwrap(),
+            syn::parse(quote!(Self::#method_name #turbofish(#(#param_ids),*)).into()).unwrap(),
+        )
+    };
+
+    if cfg!(feature = "log") {
+        let name = method_name.to_string();
+        syn::parse(
+            quote!({
+                ::log::debug!("invoking {}", #name);
+                #call
+            })
+            .into(),
         )
+        .unwrap()
+    } else {
+        call
     }
 }
 
-/// Given a list of methods bound together by some invoke function, generate an enum to
-/// represent them. Namely, if methods = [fn1, fn2, fn3, ... fnm] and struct_ident = struct_name,
-/// then this will create an enum with members fn1, fn2, fn3, ... fnm. The created enum will
-/// implement Debug, Clone, Copy, and TryFrom<&str>. &str will implement From<enum_name>.
-fn create_enum(methods: &Vec<&ImplItemMethod>, struct_ident: &Ident, name: &Option<String>) -> TokenStream {
-    // Get list of identifiers from methods
-    let identifiers = methods
-        .into_iter()
-        .map(|im| im.sig.ident.clone())
-        .collect::<Vec<_>>();
+/// Helper function to get the type a consumer closure receives for results of type result_type,
+/// given how results are passed to it
+fn consumed_type(consumer_mode: ConsumerMode, result_type: Type) -> Type {
+    match consumer_mode {
+        ConsumerMode::Owned => result_type,
+        ConsumerMode::Ref => syn::parse(quote!(&#result_type).into()).unwrap(),
+    }
+}
+
+/// Helper function to get the expression passed to a consumer closure for a result, given how
+/// results are passed to it
+fn consumed_expr(consumer_mode: ConsumerMode, result: &Expr) -> Expr {
+    match consumer_mode {
+        ConsumerMode::Owned => result.clone(),
+        ConsumerMode::Ref => syn::parse(quote!(&#result).into()).unwrap(),
+    }
+}
 
+/// Given a list of methods bound together by some invoke function, generate an enum to represent
+/// them. Namely, if methods = [fn1, fn2, fn3, ... fnm] and struct_ident = struct_name, then this
+/// will create an enum with members fn1, fn2, fn3, ... fnm, or rather the identifiers aligned with
+/// methods, which differ from the names of the methods renamed by the rename argument. The created
+/// enum will implement Debug, Clone, Copy, PartialEq, Eq, Hash, TryFrom<&str> and FromStr. &str and
+/// usize will implement From<enum_name>. Besides the COUNT and NAMES consts mirroring METHOD_COUNT
+/// and METHOD_LIST (so counting every method, even those gated by #[cfg]), iter() and the VARIANTS
+/// array it iterates over (holding only the variants compiled), the enum gets the inherent const fn
+/// index and from_index, the Option returning counterpart of TryFrom<usize>. Each variant carries
+/// the doc attributes of its method (so the variants of methods marked #[doc(hidden)] are hidden as
+/// well), and the variants and conversion arms of methods gated by #[cfg] carry the same #[cfg]; no
+/// other attributes are forwarded. If panic_conversions is true, the enum also implements
+/// From<usize>, panicking on indices out of range, and otherwise TryFrom<usize> (the two can't
+/// coexist, as From<usize> already provides TryFrom<usize>). If a default_variant is given, the
+/// enum implements Default, returning that variant. The inherent serialized_name and
+/// from_serialized_name map the variants to and from serialized_names, which are aligned with
+/// methods. If a repr is given, the enum is represented as it, with each variant's index as its
+/// discriminant, and gets the accessor as_{repr}. The enum is declared with visibility vis.
+#[allow(clippy::too_many_arguments)]
+fn create_enum(
+    methods: &Vec<&ImplItemMethod>,
+    identifiers: &[Ident],
+    struct_ident: &Ident,
+    enum_name: &Ident,
+    panic_conversions: bool,
+    default_variant: &Option<Ident>,
+    serialized_names: &[String],
+    repr: &Option<Ident>,
+    vis: &Visibility,
+) -> TokenStream {
     // Get list of identifiers as strings
     let names = identifiers
         .iter()
         .map(|i| i.to_string())
         .collect::<Vec<_>>();
 
+    // Get the #[cfg] attributes of each method, so that its variant and conversion arms compile out
+    // with it
+    let cfgs = methods
+        .iter()
+        .map(|&method| cfg_attrs(method))
+        .collect::<Vec<_>>();
+
+    // Get the doc attributes of each method, so its variant shows the same docs (and is hidden if
+    // the method is #[doc(hidden)])
+    let doc_attrs = methods
+        .iter()
+        .map(|im| {
+            im.attrs
+                .iter()
+                .filter(|attr| attr.path.is_ident("doc"))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
     let num_members = identifiers.len();
+    let indices = (0..num_members).collect::<Vec<_>>();
+
+    // The names of the functions themselves, for COUNT and NAMES mirroring METHOD_COUNT and
+    // METHOD_LIST
+    let method_names = methods
+        .iter()
+        .map(|method| method.sig.ident.to_string())
+        .collect::<Vec<_>>();
 
-    // Generate enum name
-    let enum_name = generate_enum_name(struct_ident, name);
+    // With a repr, each variant's discriminant is its index
+    let enum_declaration: ItemEnum = match repr {
+        Some(repr) => {
+            let discriminants = indices
+                .iter()
+                .map(|index| syn::LitInt::new(&index.to_string(), Span::call_site()));
+            syn::parse(
+                quote!(
+                    #[allow(non_camel_case_types)]
+                    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+                    #[repr(#repr)]
+                    #vis enum #enum_name {
+                    #(#(#cfgs)* #(#doc_attrs)* #identifiers = #discriminants),*
+                })
+                .into(),
+            )
+            .unwrap()
+        }
+        None => syn::parse(
+            quote!(
+                #[allow(non_camel_case_types)]
+                #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+                #vis enum #enum_name {
+                #(#(#cfgs)* #(#doc_attrs)* #identifiers),*
+            })
+            .into(),
+        )
+        .unwrap(),
+    };
 
-    let enum_declaration: ItemEnum = syn::parse(
-        quote!(
-            #[allow(non_camel_case_types)]
-            #[derive(Debug, Clone, Copy)]
-            pub enum #enum_name {
-            #(#identifiers),*
+    // The number of variants, which has to be counted by the compiler if some of them are gated
+    let variant_count = if cfgs.iter().all(|method_cfgs| method_cfgs.is_empty()) {
+        quote!(#num_members)
+    } else {
+        quote!({
+            let mut count = 0usize;
+            #(#(#cfgs)* {
+                count += 1;
+            })*
+            count
         })
-        .into(),
-    )
-    .unwrap();
+    };
 
+    // iter() copies the variants into a static (named accordingly, to keep non_upper_case_globals
+    // quiet in user crates) so that the 'static references it yields all point into one array
     let enum_impl: ItemImpl = syn::parse(
         quote!(impl #enum_name {
+            /// The number of functions in the impl block, which like METHOD_COUNT includes those
+            /// compiled out by #[cfg]
+            pub const COUNT: usize = #num_members;
+
+            /// The names of the functions in the impl block, which like METHOD_LIST include those
+            /// compiled out by #[cfg]
+            pub const NAMES: [&'static str; #num_members] = [#(#method_names),*];
+
+            /// The variants of the functions compiled under the active cfgs, in impl block order
+            pub const VARIANTS: [#enum_name; #variant_count] =
+                [#(#(#cfgs)* Self::#identifiers),*];
+
             pub fn iter() -> impl Iterator<Item=&'static #enum_name> {
-                use #enum_name::*;
-                static members: [#enum_name; #num_members] = [#(#identifiers),*];
-                members.iter()
+                static MEMBERS: [#enum_name; #variant_count] = #enum_name::VARIANTS;
+                MEMBERS.iter()
+            }
+
+            pub const fn index(self) -> usize {
+                match self {
+                    #(#(#cfgs)* Self::#identifiers => #indices,)*
+                }
+            }
+
+            pub fn from_index(index: usize) -> Option<Self> {
+                match index {
+                    #(#(#cfgs)* #indices => Some(Self::#identifiers),)*
+                    _ => None,
+                }
+            }
+
+            pub const fn serialized_name(self) -> &'static str {
+                match self {
+                    #(#(#cfgs)* Self::#identifiers => #serialized_names,)*
+                }
+            }
+
+            pub fn from_serialized_name(name: &str) -> Option<Self> {
+                match name {
+                    #(#(#cfgs)* #serialized_names => Some(Self::#identifiers),)*
+                    _ => None,
+                }
             }
         })
         .into(),
@@ -945,7 +4372,7 @@ fn create_enum(methods: &Vec<&ImplItemMethod>, struct_ident: &Ident, name: &Opti
                 type Error = &'static str;
                 fn try_from(value: &str) -> Result<Self, Self::Error> {
                     match value {
-                        #(#names => Ok(Self::#identifiers),)*
+                        #(#(#cfgs)* #names => Ok(Self::#identifiers),)*
                         _ => Err("Input str does not match any enums in Self!")
                     }
                 }
@@ -955,13 +4382,27 @@ fn create_enum(methods: &Vec<&ImplItemMethod>, struct_ident: &Ident, name: &Opti
     )
     .unwrap();
 
+    // FromStr shares the error of TryFrom<&str>, so that str::parse works on the enum too
+    let from_str: ItemImpl = syn::parse(
+        quote!(
+            impl ::core::str::FromStr for #enum_name {
+                type Err = &'static str;
+                fn from_str(value: &str) -> Result<Self, Self::Err> {
+                    <Self as TryFrom<&str>>::try_from(value)
+                }
+            }
+        )
+        .into(),
+    )
+    .unwrap();
+
     let from_num: ItemImpl = syn::parse(
         quote!(
             impl From<#enum_name> for &str {
                 fn from(en: #enum_name) -> Self {
                     use #enum_name::*;
                     match en {
-                        #(#identifiers => #names,)*
+                        #(#(#cfgs)* #identifiers => #names,)*
                     }
                 }
             }
@@ -972,14 +4413,524 @@ fn create_enum(methods: &Vec<&ImplItemMethod>, struct_ident: &Ident, name: &Opti
 
     let mut enum_tokenstream: TokenStream = enum_declaration.into_token_stream().into();
     enum_tokenstream.extend::<TokenStream>(enum_impl.into_token_stream().into());
+
+    if let Some(repr) = repr {
+        // An accessor named after the repr, so the discriminant can be read without an as cast
+        let accessor = format_ident!("as_{}", repr);
+        let repr_impl: ItemImpl = syn::parse(
+            quote!(impl #enum_name {
+                pub const fn #accessor(self) -> #repr {
+                    self as #repr
+                }
+            })
+            .into(),
+        )
+        .unwrap();
+        enum_tokenstream.extend::<TokenStream>(repr_impl.into_token_stream().into());
+    }
     enum_tokenstream.extend::<TokenStream>(try_from_str.into_token_stream().into());
+    enum_tokenstream.extend::<TokenStream>(from_str.into_token_stream().into());
     enum_tokenstream.extend::<TokenStream>(from_num.into_token_stream().into());
+
+    let from_enum: ItemImpl = syn::parse(
+        quote!(
+            impl From<#enum_name> for usize {
+                fn from(en: #enum_name) -> Self {
+                    use #enum_name::*;
+                    match en {
+                        #(#(#cfgs)* #identifiers => #indices,)*
+                    }
+                }
+            }
+        )
+        .into(),
+    )
+    .unwrap();
+    enum_tokenstream.extend::<TokenStream>(from_enum.into_token_stream().into());
+
+    if panic_conversions {
+        let from_usize: ItemImpl = syn::parse(
+            quote!(
+                impl From<usize> for #enum_name {
+                    fn from(index: usize) -> Self {
+                        match index {
+                            #(#(#cfgs)* #indices => Self::#identifiers,)*
+                            _ => panic!(
+                                "Index {} is out of range for the {} functions of {}!",
+                                index,
+                                #num_members,
+                                stringify!(#struct_ident)
+                            ),
+                        }
+                    }
+                }
+            )
+            .into(),
+        )
+        .unwrap();
+        enum_tokenstream.extend::<TokenStream>(from_usize.into_token_stream().into());
+    } else {
+        let try_from_usize: ItemImpl = syn::parse(
+            quote!(
+                impl TryFrom<usize> for #enum_name {
+                    type Error = &'static str;
+                    fn try_from(index: usize) -> Result<Self, Self::Error> {
+                        match index {
+                            #(#(#cfgs)* #indices => Ok(Self::#identifiers),)*
+                            _ => Err("Input usize is out of range for the enums in Self!")
+                        }
+                    }
+                }
+            )
+            .into(),
+        )
+        .unwrap();
+        enum_tokenstream.extend::<TokenStream>(try_from_usize.into_token_stream().into());
+    }
+
+    if cfg!(feature = "serde") {
+        // Variants are (de)serialized as their serialized names, so renaming a function marked
+        // with #[invoke_serde_name] doesn't break persisted selections
+        let serde_impls = quote!(
+            impl ::serde::Serialize for #enum_name {
+                fn serialize<S: ::serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> ::core::result::Result<S::Ok, S::Error> {
+                    serializer.serialize_str(self.serialized_name())
+                }
+            }
+
+            impl<'de> ::serde::Deserialize<'de> for #enum_name {
+                fn deserialize<D: ::serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> ::core::result::Result<Self, D::Error> {
+                    struct InvokeImplVisitor;
+
+                    impl<'de> ::serde::de::Visitor<'de> for InvokeImplVisitor {
+                        type Value = #enum_name;
+
+                        fn expecting(
+                            &self,
+                            formatter: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
+                            formatter.write_str(concat!(
+                                "the name of a function of ",
+                                stringify!(#struct_ident)
+                            ))
+                        }
+
+                        fn visit_str<E: ::serde::de::Error>(
+                            self,
+                            value: &str,
+                        ) -> ::core::result::Result<#enum_name, E> {
+                            #enum_name::from_serialized_name(value).ok_or_else(|| {
+                                E::unknown_variant(value, &[#(#serialized_names),*])
+                            })
+                        }
+                    }
+
+                    deserializer.deserialize_str(InvokeImplVisitor)
+                }
+            }
+        );
+        enum_tokenstream.extend::<TokenStream>(serde_impls.into());
+    }
+
+    if let Some(function) = default_variant {
+        // default_variant names the function, whose variant may be renamed
+        let position = methods
+            .iter()
+            .position(|method| &method.sig.ident == function)
+            .unwrap();
+        let variant = &identifiers[position];
+
+        // Default is only implemented while the variant is there
+        let variant_cfgs = cfg_attrs(methods[position]);
+        let default_impl: ItemImpl = syn::parse(
+            quote!(
+                #(#variant_cfgs)*
+                impl Default for #enum_name {
+                    fn default() -> Self {
+                        Self::#variant
+                    }
+                }
+            )
+            .into(),
+        )
+        .unwrap();
+        enum_tokenstream.extend::<TokenStream>(default_impl.into_token_stream().into());
+    }
+
     enum_tokenstream
 }
 
+/// Generates the iterator struct returned by invoke_all_iter, which yields the results of boxed
+/// thunks. Unlike mapping over the thunks, it implements nth by dropping the thunks it skips, so
+/// skipped functions are never called. The struct is declared with visibility vis.
+fn create_iter_struct(
+    struct_ident: &Ident,
+    name: &Option<String>,
+    vis: &Visibility,
+) -> TokenStream {
+    let iter_name = generate_iter_name(struct_ident, name);
+    quote!(
+        #vis struct #iter_name<'a, R, const N: usize>(
+            ::core::array::IntoIter<::std::boxed::Box<dyn FnOnce() -> R + 'a>, N>,
+        );
+
+        impl<'a, R, const N: usize> Iterator for #iter_name<'a, R, N> {
+            type Item = R;
+
+            fn next(&mut self) -> Option<R> {
+                self.0.next().map(|thunk| thunk())
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.0.size_hint()
+            }
+
+            fn nth(&mut self, n: usize) -> Option<R> {
+                self.0.nth(n).map(|thunk| thunk())
+            }
+        }
+    )
+    .into()
+}
+
+/// Generates the module of free functions for the free_fns argument, named after the struct in
+/// snake_case followed by _invoke and the name, if any. Each invoke function (generated for the
+/// matching entry of invoke_types) gets a free function named without its invoke_ prefix (and
+/// without any prefix or name, so invoke_enum becomes r#enum) that takes the same parameters and
+/// delegates to it. The module glob imports its parent, so the types in the signatures resolve as
+/// they do in the impl block, and Self is replaced by the struct. The module is declared with
+/// visibility vis.
+fn create_free_fns_module(
+    invoke_functions: &[ImplItem],
+    invoke_types: &[InvokeType],
+    struct_ident: &Ident,
+    name: &Option<String>,
+    vis: &Visibility,
+) -> TokenStream {
+    let free_fns = invoke_functions
+        .iter()
+        .zip(invoke_types)
+        .filter_map(|(item, &invoke_type)| match item {
+            ImplItem::Method(invoke_function) => Some((invoke_function, invoke_type)),
+            _ => None,
+        })
+        .map(|(invoke_function, invoke_type)| {
+            let invoke_ident = &invoke_function.sig.ident;
+            // invoke_enum becomes r#enum, as enum is a keyword
+            let free_name = generate_consumer_mode_key(invoke_type);
+            let free_ident = syn::parse_str::<Ident>(free_name)
+                .unwrap_or_else(|_| Ident::new_raw(free_name, Span::call_site()));
+            let mut free_sig = Signature {
+                ident: free_ident,
+                ..invoke_function.sig.clone()
+            };
+            SelfTypeReplacer { struct_ident }.visit_signature_mut(&mut free_sig);
+            let target = syn::parse(quote!(super::#struct_ident::#invoke_ident).into()).unwrap();
+            create_delegating_fn(
+                &syn::parse(quote!(pub).into()).unwrap(),
+                &free_sig,
+                &target,
+                None,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let module_ident = match name {
+        Some(n) => format_ident!(
+            "{}_invoke_{}",
+            to_snake_case(&struct_ident.to_string()),
+            n.to_lowercase()
+        ),
+        None => format_ident!("{}_invoke", to_snake_case(&struct_ident.to_string())),
+    };
+    quote!(
+        #vis mod #module_ident {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #(#free_fns)*
+        }
+    )
+    .into()
+}
+
+/// Generates the struct named invoker, holding a reference to the self type of the impl block
+/// input, with a method per invoke function that calls it on the held reference. The methods are
+/// named like the free functions of free_fns (all, subset, enumerated, r#enum...), so that the
+/// invoke functions can be called fluently, as in WidgetInvoker::new(&widget).all(consumer). The
+/// struct and its methods are declared with visibility vis.
+fn create_invoker_struct(
+    invoke_functions: &[ImplItem],
+    invoke_types: &[InvokeType],
+    input: &ItemImpl,
+    struct_ident: &Ident,
+    invoker: &Ident,
+    vis: &Visibility,
+) -> TokenStream {
+    let receiver = syn::parse(quote!(self.0).into()).unwrap();
+    let methods = invoke_functions
+        .iter()
+        .zip(invoke_types)
+        .filter_map(|(item, &invoke_type)| match item {
+            ImplItem::Method(invoke_function) => Some((invoke_function, invoke_type)),
+            _ => None,
+        })
+        .map(|(invoke_function, invoke_type)| {
+            let invoke_ident = &invoke_function.sig.ident;
+            let method_name = generate_consumer_mode_key(invoke_type);
+            let method_ident = syn::parse_str::<Ident>(method_name)
+                .unwrap_or_else(|_| Ident::new_raw(method_name, Span::call_site()));
+            let mut method_sig = Signature {
+                ident: method_ident,
+                ..invoke_function.sig.clone()
+            };
+            // Self refers to the invoker inside its impl block
+            SelfTypeReplacer { struct_ident }.visit_signature_mut(&mut method_sig);
+            let target = syn::parse(quote!(#struct_ident::#invoke_ident).into()).unwrap();
+            create_delegating_fn(vis, &method_sig, &target, Some(&receiver))
+        })
+        .collect::<Vec<_>>();
+
+    // The invoker takes the generics of the impl block, after the lifetime of the reference it holds
+    let self_ty = &input.self_ty;
+    let lifetime = Lifetime::new("'invoke_impl", Span::call_site());
+    let mut generics = input.generics.clone();
+    generics.params.insert(
+        0,
+        GenericParam::Lifetime(LifetimeDef::new(lifetime.clone())),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote!(
+        #vis struct #invoker #impl_generics (&#lifetime #self_ty) #where_clause;
+
+        impl #impl_generics #invoker #ty_generics #where_clause {
+            #vis fn new(target: &#lifetime #self_ty) -> Self {
+                Self(target)
+            }
+
+            #(#methods)*
+        }
+    )
+    .into()
+}
+
+/// Creates a free function with visibility vis and signature sig that passes its parameters on to
+/// the function at target, preceded by receiver if given, awaiting it if sig is async and calling
+/// it in an unsafe block if sig is unsafe. Parameters destructured by a pattern are bound to arg{i}
+/// first, like in the invoke functions, and generic parameters are left to be inferred.
+fn create_delegating_fn(
+    vis: &Visibility,
+    sig: &Signature,
+    target: &syn::Path,
+    receiver: Option<&Expr>,
+) -> ItemFn {
+    let mut sig = sig.clone();
+    bind_pattern_params(&mut sig);
+
+    // The parameters are only passed on, so none of them need to be mut
+    for fnarg in sig.inputs.iter_mut() {
+        if let Typed(pattype) = fnarg {
+            if let Pat::Ident(patident) = &mut *pattype.pat {
+                patident.mutability = None;
+            }
+        }
+    }
+
+    let args = forwarded_param_idents(&sig);
+    let receiver = receiver.into_iter();
+    let call = quote!(#target(#(#receiver,)* #(#args),*));
+    let call = if sig.asyncness.is_some() {
+        quote!(#call.await)
+    } else {
+        call
+    };
+    let call = if sig.unsafety.is_some() {
+        quote!(unsafe { #call })
+    } else {
+        call
+    };
+    // Like the invoke functions, it can take more parameters than clippy likes
+    syn::parse(quote!(#[allow(clippy::too_many_arguments)] #vis #sig { #call }).into()).unwrap()
+}
+
+/// Creates the method invoke_mod gathers a free function of a module as: it has the attributes,
+/// visibility and signature of the function, and calls it. The markers of invoke_impl, such as
+/// #[invoke_reference], are moved from the function to the method, where invoke_impl takes them.
+fn create_mod_wrapper(function: &mut ItemFn) -> ImplItemMethod {
+    let attrs = function.attrs.clone();
+    function.attrs.retain(|attr| {
+        !attr.path.is_ident("invoke_reference") && !attr.path.is_ident("invoke_serde_name")
+    });
+    let ident = &function.sig.ident;
+    let target = syn::parse(quote!(#ident).into()).unwrap();
+    let delegating = create_delegating_fn(&function.vis, &function.sig, &target, None);
+    ImplItemMethod {
+        attrs,
+        vis: delegating.vis,
+        defaultness: None,
+        sig: delegating.sig,
+        block: *delegating.block,
+    }
+}
+
+/// Helper function to get the identifier an item of a module declares, if it declares exactly one
+fn item_ident(item: &Item) -> Option<&Ident> {
+    match item {
+        Item::Const(item) => Some(&item.ident),
+        Item::Enum(item) => Some(&item.ident),
+        Item::Fn(item) => Some(&item.sig.ident),
+        Item::Mod(item) => Some(&item.ident),
+        Item::Static(item) => Some(&item.ident),
+        Item::Struct(item) => Some(&item.ident),
+        Item::Trait(item) => Some(&item.ident),
+        Item::Type(item) => Some(&item.ident),
+        Item::Union(item) => Some(&item.ident),
+        _ => None,
+    }
+}
+
+/// Helper struct to replace Self in the paths of a signature with the struct it refers to, for
+/// signatures moved out of the impl block
+struct SelfTypeReplacer<'a> {
+    struct_ident: &'a Ident,
+}
+
+impl VisitMut for SelfTypeReplacer<'_> {
+    fn visit_path_mut(&mut self, path: &mut syn::Path) {
+        if let Some(first) = path.segments.first_mut() {
+            if first.ident == "Self" {
+                first.ident = self.struct_ident.clone();
+            }
+        }
+        visit_mut::visit_path_mut(self, path);
+    }
+}
+
+/// Helper function to get the #[cfg] attributes of a method, which are copied onto everything
+/// generated for it (enum variants, match arms and statements) so that it compiles out together
+/// with the method
+fn cfg_attrs(method: &ImplItemMethod) -> Vec<&Attribute> {
+    method
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("cfg"))
+        .collect()
+}
+
+/// Generates the struct returned by invoke_all_results, which wraps the results of every function
+/// in impl block order and implements Index for both the enum and usize. The struct is declared
+/// with visibility vis.
+fn create_results_struct(
+    struct_ident: &Ident,
+    name: &Option<String>,
+    enum_name: &Ident,
+    vis: &Visibility,
+) -> TokenStream {
+    let results_name = generate_results_name(struct_ident, name);
+    quote!(
+        #[derive(Debug, Clone, PartialEq)]
+        #vis struct #results_name<R>(::std::vec::Vec<R>);
+
+        impl<R> #results_name<R> {
+            pub fn into_vec(self) -> ::std::vec::Vec<R> {
+                self.0
+            }
+        }
+
+        impl<R> ::core::ops::Index<#enum_name> for #results_name<R> {
+            type Output = R;
+
+            fn index(&self, variant: #enum_name) -> &R {
+                &self.0[usize::from(variant)]
+            }
+        }
+
+        impl<R> ::core::ops::Index<usize> for #results_name<R> {
+            type Output = R;
+
+            fn index(&self, index: usize) -> &R {
+                &self.0[index]
+            }
+        }
+    )
+    .into()
+}
+
+/// Safety function to check that base_method doesn't return a type that is clearly unsized (str, a
+/// slice or a bare trait object). Such a return is already invalid, but the invoke functions
+/// collecting results would add errors of their own deep inside the generated code. Returns an
+/// error spanning the return type, suggesting indirection, if not true.
+fn validate_sized_return(base_method: &ImplItemMethod) -> Result<(), syn::Error> {
+    let return_type = match &base_method.sig.output {
+        ReturnType::Type(_, bx) => &**bx,
+        ReturnType::Default => return Ok(()),
+    };
+    let unsized_return = match return_type {
+        Type::Slice(_) | Type::TraitObject(_) => true,
+        Type::Path(tp) => tp.qself.is_none() && tp.path.is_ident("str"),
+        _ => false,
+    };
+    if unsized_return {
+        Err(syn::Error::new_spanned(
+            return_type,
+            format!(
+                "`{}` returns the unsized type `{}`, which invoke_impl can't collect; return it \
+                 behind a reference or a Box instead",
+                base_method.sig.ident,
+                return_type.to_token_stream()
+            ),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Safety function to check that either all of methods take self or none of them do, as one invoke
+/// function can't call methods (needing self) and associated functions (not taking it) alike.
+/// Returns an error spanning the first function whose receiver differs from base_method's, if not
+/// true.
+fn validate_receivers(
+    base_method: &ImplItemMethod,
+    methods: &[&ImplItemMethod],
+) -> Result<(), syn::Error> {
+    let base_is_method = base_method.sig.receiver().is_some();
+    match methods
+        .iter()
+        .find(|method| method.sig.receiver().is_some() != base_is_method)
+    {
+        Some(method) => {
+            let (with, without) = if base_is_method {
+                (&base_method.sig.ident, &method.sig.ident)
+            } else {
+                (&method.sig.ident, &base_method.sig.ident)
+            };
+            Err(syn::Error::new_spanned(
+                &method.sig,
+                format!(
+                    "`{}` takes self but `{}` doesn't; an invoke_impl block can't mix methods and \
+                    associated functions, as no invoke function could call both",
+                    with, without
+                ),
+            ))
+        }
+        None => Ok(()),
+    }
+}
+
 /// Safety function to check that base_method and all other methods share identical signatures
-/// except for identity (names). Panics if not true.
-fn validate_signatures(base_method: &ImplItemMethod, methods: &Vec<&ImplItemMethod>) {
+/// except for identity (names). Returns an error spanning the signature of the first method that
+/// differs, naming which part of it diverged, if not true. As every invoke function depends on the
+/// shared return type, methods returning another type get an error of their own, spanning the
+/// return type and naming both types.
+fn validate_signatures(
+    base_method: &ImplItemMethod,
+    methods: &Vec<&ImplItemMethod>,
+) -> Result<(), syn::Error> {
     let base_signature = Signature {
         ident: Ident::new("name", Span::call_site()),
         ..base_method.sig.clone()
@@ -997,33 +4948,446 @@ fn validate_signatures(base_method: &ImplItemMethod, methods: &Vec<&ImplItemMeth
         ..base_method.clone()
     };
 
-    // Compare against each method:
-    for &method in methods {
-        let signature = Signature {
-            ident: Ident::new("name", Span::call_site()),
-            ..method.sig.clone()
+    // Compare against each method:
+    for &method in methods {
+        // Qualifiers are easily missed on one function, so name the one that diverged
+        let qualifiers = [
+            (
+                "async",
+                method.sig.asyncness.is_some(),
+                base_method.sig.asyncness.is_some(),
+            ),
+            (
+                "const",
+                method.sig.constness.is_some(),
+                base_method.sig.constness.is_some(),
+            ),
+            (
+                "unsafe",
+                method.sig.unsafety.is_some(),
+                base_method.sig.unsafety.is_some(),
+            ),
+        ];
+        if let Some(&(qualifier, has, _)) = qualifiers.iter().find(|(_, has, base)| has != base) {
+            let (with, without) = if has {
+                (&method.sig.ident, &base_method.sig.ident)
+            } else {
+                (&base_method.sig.ident, &method.sig.ident)
+            };
+            return Err(syn::Error::new_spanned(
+                &method.sig,
+                format!(
+                    "`{}` is {} but `{}` is not; all functions in an invoke_impl block must share \
+                    identical signatures",
+                    with, qualifier, without
+                ),
+            ));
+        }
+
+        if method.sig.output != base_method.sig.output {
+            let span = match &method.sig.output {
+                ReturnType::Default => method.sig.to_token_stream(),
+                output => output.to_token_stream(),
+            };
+            return Err(syn::Error::new_spanned(
+                span,
+                format!(
+                    "`{}` returns `{}` but `{}` returns `{}`; all functions in an invoke_impl \
+                    block must share a return type",
+                    method.sig.ident,
+                    return_type_name(&method.sig.output),
+                    base_method.sig.ident,
+                    return_type_name(&base_method.sig.output)
+                ),
+            ));
+        }
+
+        let signature = Signature {
+            ident: Ident::new("name", Span::call_site()),
+            ..method.sig.clone()
+        };
+
+        // Create standard ImplItemMethod to compare against
+        let methodimpl = ImplItemMethod {
+            sig: signature,
+            attrs: vec![],
+            block: Block {
+                brace_token: Default::default(),
+                stmts: vec![],
+            },
+            ..method.clone()
+        };
+
+        if method_comparison != methodimpl {
+            // Narrow down which part of the signature diverged for the error message
+            let diverged = if method.sig.generics != base_method.sig.generics {
+                "generic parameters"
+            } else if method.sig.inputs != base_method.sig.inputs {
+                "parameters"
+            } else {
+                "abi or visibility"
+            };
+            return Err(syn::Error::new_spanned(
+                &method.sig,
+                format!(
+                    "`{}` differs from `{}` in its {}; all functions in an invoke_impl block \
+                    must share identical signatures",
+                    method.sig.ident, base_method.sig.ident, diverged
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Helper function to get the name of an item of an impl block, if it has one
+fn impl_item_name(item: &ImplItem) -> Option<&Ident> {
+    match item {
+        ImplItem::Method(method) => Some(&method.sig.ident),
+        ImplItem::Const(constant) => Some(&constant.ident),
+        ImplItem::Type(ty) => Some(&ty.ident),
+        _ => None,
+    }
+}
+
+/// Safety function to check that the names of the items generated for an impl block neither collide
+/// with each other nor with the items already in the impl block, which the naming arguments
+/// (name and prefix) can bring about. Returns an error naming the first collision found, spanning
+/// the existing item if there is one.
+fn validate_generated_names(input: &ItemImpl, generated: &[ImplItem]) -> Result<(), syn::Error> {
+    let mut seen = HashSet::new();
+    for ident in generated.iter().filter_map(impl_item_name) {
+        if let Some(existing) = input
+            .items
+            .iter()
+            .filter_map(impl_item_name)
+            .find(|existing| *existing == ident)
+        {
+            return Err(syn::Error::new_spanned(
+                existing,
+                format!(
+                    "{} is also generated by invoke_impl; rename it or change the name or prefix \
+                    argument",
+                    ident
+                ),
+            ));
+        }
+        if !seen.insert(ident) {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                format!(
+                    "invoke_impl would generate {} twice; change the name or prefix argument",
+                    ident
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Safety function to check that none of the invoked functions is named like one of the invoke
+/// functions, such as a hand-written invoke_all. Such a function would be invoked itself, and
+/// usually fail the signature check first, hiding the collision. invoke_all_par and
+/// invoke_all_par_each are only reserved if parallel is given, and invoke_all_from only if
+/// args_struct is. Returns an error spanning the first such function, suggesting the arguments that
+/// resolve the collision, if not true.
+fn validate_method_names(
+    methods: &[&ImplItemMethod],
+    name: &Option<String>,
+    prefix: &Option<String>,
+    verb: &Option<String>,
+    parallel: bool,
+    args_struct: bool,
+) -> Result<(), syn::Error> {
+    let mut invoke_types = vec![
+        InvokeType::Specified(SpecificationType::Enum),
+        InvokeType::Specified(SpecificationType::Enumerated),
+        InvokeType::ByName,
+        InvokeType::SpecifiedAll(SpecificationType::Enum),
+        InvokeType::SpecifiedAll(SpecificationType::Enumerated),
+        InvokeType::Subset,
+        InvokeType::UniqueSubset,
+        InvokeType::CheckedSubset,
+        InvokeType::All,
+        InvokeType::AllRev,
+        InvokeType::AllThen,
+        InvokeType::Progress,
+        InvokeType::Tuple,
+        InvokeType::Batch,
+        InvokeType::Collect,
+        InvokeType::EnumeratedCollect,
+        InvokeType::Deque,
+        InvokeType::NamedMap,
+        InvokeType::CollectIndices,
+        InvokeType::Extend,
+        InvokeType::Into,
+        InvokeType::Fold,
+        InvokeType::EnumFold,
+        InvokeType::With,
+        InvokeType::Results,
+        InvokeType::EnumCollect,
+        InvokeType::Partition,
+        InvokeType::LazySubset,
+        InvokeType::Fastest,
+        InvokeType::FirstNonPanicking,
+        InvokeType::FirstSome,
+        InvokeType::Until,
+        InvokeType::EnumeratedUntil,
+        InvokeType::Try,
+        InvokeType::CountOk,
+        InvokeType::AllAsync,
+        InvokeType::AllJoin,
+        InvokeType::Iter,
+    ];
+    if parallel {
+        invoke_types.extend([InvokeType::Par, InvokeType::ParEach]);
+    }
+    let mut invoke_names = invoke_types
+        .into_iter()
+        .map(|invoke_type| generate_invoke_name(name, prefix, verb, invoke_type))
+        .collect::<Vec<_>>();
+    if args_struct {
+        invoke_names.push(generate_invoke_all_from_name(name, prefix, verb));
+    }
+    match methods
+        .iter()
+        .find(|method| invoke_names.contains(&method.sig.ident))
+    {
+        Some(method) => Err(syn::Error::new_spanned(
+            &method.sig.ident,
+            format!(
+                "`{}` is the name of a function generated by invoke_impl; rename it, skip it, or \
+                 pass name(\"...\") to suffix the generated names",
+                method.sig.ident
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Helper function to check whether method is to be invoked, given the functions skipped and, if
+/// given, the only functions included
+fn is_selected(
+    method: &ImplItemMethod,
+    skip: &HashSet<String>,
+    include: &Option<HashSet<String>>,
+) -> bool {
+    let ident = method.sig.ident.to_string();
+    !skip.contains(&ident) && include.as_ref().is_none_or(|hs| hs.contains(&ident))
+}
+
+/// Removes the #[invoke_reference] attribute, which isn't a real attribute, from every function in
+/// the impl block. Returns the identifier of the function that carried it, if any, or an error
+/// spanning the second marker if more than one is found. Every marker is removed either way, so
+/// the error isn't joined by errors about an unknown attribute.
+fn take_reference_marker(input: &mut ItemImpl) -> Result<Option<Ident>, syn::Error> {
+    let mut reference = None;
+    let mut duplicate = None;
+    for item in input.items.iter_mut() {
+        if let ImplItem::Method(method) = item {
+            let (markers, attrs) = std::mem::take(&mut method.attrs)
+                .into_iter()
+                .partition::<Vec<_>, _>(|attr| attr.path.is_ident("invoke_reference"));
+            method.attrs = attrs;
+            for marker in markers {
+                if reference.is_none() {
+                    reference = Some(method.sig.ident.clone());
+                } else if duplicate.is_none() {
+                    duplicate = Some(marker);
+                }
+            }
+        }
+    }
+    match duplicate {
+        Some(marker) => Err(syn::Error::new_spanned(
+            marker,
+            "#[invoke_reference] can only mark one function",
+        )),
+        None => Ok(reference),
+    }
+}
+
+/// Removes the #[invoke_serde_name("...")] attribute, which isn't a real attribute, from every
+/// function in the impl block. Returns the identifiers of the functions that carried it paired with
+/// the names it gave them, in impl block order, or an error spanning the first malformed or
+/// repeated attribute. Every attribute is removed either way, so the error isn't joined by errors
+/// about an unknown attribute.
+fn take_serde_names(input: &mut ItemImpl) -> Result<Vec<(Ident, LitStr)>, syn::Error> {
+    let mut serde_names = vec![];
+    let mut error = None;
+    for item in input.items.iter_mut() {
+        if let ImplItem::Method(method) = item {
+            let (overrides, attrs) = std::mem::take(&mut method.attrs)
+                .into_iter()
+                .partition::<Vec<_>, _>(|attr| attr.path.is_ident("invoke_serde_name"));
+            method.attrs = attrs;
+            for (i, attr) in overrides.into_iter().enumerate() {
+                let parsed = if i > 0 {
+                    Err(syn::Error::new_spanned(
+                        &attr,
+                        "#[invoke_serde_name] can only be given once per function",
+                    ))
+                } else {
+                    attr.parse_args::<LitStr>().map_err(|_| {
+                        syn::Error::new_spanned(
+                            &attr,
+                            "#[invoke_serde_name] takes a single string literal",
+                        )
+                    })
+                };
+                match parsed {
+                    Ok(litstr) => serde_names.push((method.sig.ident.clone(), litstr)),
+                    Err(e) => {
+                        error.get_or_insert(e);
+                    }
+                }
+            }
+        }
+    }
+    match error {
+        Some(e) => Err(e),
+        None => Ok(serde_names),
+    }
+}
+
+/// Helper function to get the name each function is serialized as, aligned with methods: the name
+/// given by #[invoke_serde_name], if any, and otherwise the name of the function. Returns an error
+/// if a function given a name isn't invoked, or if two functions would be serialized as the same
+/// name, as the name couldn't be deserialized then.
+fn get_serialized_names(
+    methods: &[&ImplItemMethod],
+    serde_names: &[(Ident, LitStr)],
+) -> Result<Vec<String>, syn::Error> {
+    if let Some((ident, litstr)) = serde_names
+        .iter()
+        .find(|(ident, _)| !methods.iter().any(|method| method.sig.ident == *ident))
+    {
+        return Err(syn::Error::new_spanned(
+            litstr,
+            format!(
+                "#[invoke_serde_name] is on `{}`, which isn't one of the functions invoked by \
+                 invoke_impl",
+                ident
+            ),
+        ));
+    }
+
+    let mut serialized_names: Vec<String> = vec![];
+    for method in methods {
+        let ident = &method.sig.ident;
+        let serde_name = serde_names.iter().find(|(named, _)| named == ident);
+        let serialized_name = match serde_name {
+            Some((_, litstr)) => litstr.value(),
+            None => ident.to_string(),
         };
+        if let Some(index) = serialized_names
+            .iter()
+            .position(|name| *name == serialized_name)
+        {
+            let message = format!(
+                "`{}` and `{}` would both be serialized as \"{}\"; give one of them another \
+                 #[invoke_serde_name]",
+                methods[index].sig.ident, ident, serialized_name
+            );
+            // Point at whichever of the two was renamed into the collision
+            let renamed = serde_name.or_else(|| {
+                serde_names
+                    .iter()
+                    .find(|(named, _)| *named == methods[index].sig.ident)
+            });
+            return Err(match renamed {
+                Some((_, litstr)) => syn::Error::new_spanned(litstr, message),
+                None => syn::Error::new_spanned(ident, message),
+            });
+        }
+        serialized_names.push(serialized_name);
+    }
+    Ok(serialized_names)
+}
 
-        // Create standard ImplItemMethod to compare against
-        let methodimpl = ImplItemMethod {
-            sig: signature,
-            attrs: vec![],
-            block: Block {
-                brace_token: Default::default(),
-                stmts: vec![],
-            },
-            ..method.clone()
+/// Helper function to get the enum variant of each function, aligned with methods: the variant
+/// given by rename, if any, and otherwise the name of the function. Returns an error if a renamed
+/// function isn't invoked, or if two functions would get the same variant.
+fn get_variant_idents(
+    methods: &[&ImplItemMethod],
+    renames: &[(Ident, Ident)],
+) -> Result<Vec<Ident>, syn::Error> {
+    if let Some((ident, _)) = renames
+        .iter()
+        .find(|(ident, _)| !methods.iter().any(|method| method.sig.ident == *ident))
+    {
+        return Err(syn::Error::new_spanned(
+            ident,
+            format!(
+                "rename names `{}`, which isn't one of the functions invoked by invoke_impl",
+                ident
+            ),
+        ));
+    }
+
+    let mut variant_idents: Vec<Ident> = vec![];
+    for method in methods {
+        let ident = &method.sig.ident;
+        let rename = renames.iter().find(|(renamed, _)| renamed == ident);
+        let variant = match rename {
+            Some((_, variant)) => variant.clone(),
+            None => ident.clone(),
         };
+        if let Some(index) = variant_idents.iter().position(|taken| *taken == variant) {
+            // Point at whichever of the two was renamed into the collision, as renamed variants
+            // carry the span of their string literal
+            let renamed = if rename.is_some() {
+                &variant
+            } else {
+                &variant_idents[index]
+            };
+            return Err(syn::Error::new_spanned(
+                renamed,
+                format!(
+                    "`{}` and `{}` would both be the enum variant `{}`; rename one of them to \
+                     another variant",
+                    methods[index].sig.ident, ident, variant
+                ),
+            ));
+        }
+        variant_idents.push(variant);
+    }
+    Ok(variant_idents)
+}
 
-        if method_comparison != methodimpl {
-            panic!(
-                "ImplItemMethods different! \
-            Base Method: {:?} \
-            Method: {:?}",
-                method_comparison.to_token_stream().to_string(),
-                methodimpl.to_token_stream().to_string()
-            );
+/// Safety function to check that skip and include aren't both given, and that every function they
+/// name is in the impl block, to catch typos. Returns an error naming the problem if not true.
+fn validate_selection(
+    input: &ItemImpl,
+    skip: &HashSet<String>,
+    include: &Option<HashSet<String>>,
+) -> Result<(), syn::Error> {
+    let (selected, verb) = match include {
+        Some(_) if !skip.is_empty() => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "invoke_impl can take either skip or include, but not both",
+            ));
         }
+        Some(included) => (included, "include"),
+        None => (skip, "skip"),
+    };
+    let mut selected = selected.iter().collect::<Vec<_>>();
+    selected.sort();
+    match selected.into_iter().find(|name| {
+        !input
+            .items
+            .iter()
+            .any(|item| matches!(item, ImplItem::Method(method) if method.sig.ident == name))
+    }) {
+        Some(missing) => Err(syn::Error::new(
+            Span::call_site(),
+            format!(
+                "invoke_impl was told to {} `{}`, but there is no such function in the impl block",
+                verb, missing
+            ),
+        )),
+        None => Ok(()),
     }
 }
 
@@ -1038,93 +5402,752 @@ fn get_struct_identifier_as_path(input: &ItemImpl) -> Result<Ident, &str> {
     }
 }
 
-/// Helper function to parse the args passed into the attribute. Currently, the format parsed will
-/// be akin to #[invoke_impl(name("some_string"); clone(2, 3))] where the name field denotes what
-/// name (if any) the user wants to give the invoke_functions and enum, and copy indicates which
-/// fields of the functions or methods being invoked need to be passed via cloning due to otherwise
-/// being moves.
-fn parse_args(args: TokenStream) -> (Option<String>, Option<HashSet<usize>>) {
-    let punctuated_args = Punctuated::<MetaList, syn::Token![;]>::parse_terminated
-        .parse(args)
-        .unwrap();
-    let mut result = (None, None);
-    if punctuated_args.is_empty() {
-        // No args, go with defaults
-        result
-    } else if punctuated_args.len() == 1 || punctuated_args.len() == 2 {
-        // Need to parse at least one argument!
-        for arg in punctuated_args {
-            match arg
-                .path
-                .get_ident()
-                .cloned()
-                .unwrap()
-                .to_string()
-                .to_lowercase()
-                .as_str()
-            {
-                "name" => {
-                    if result.0.is_some() {
-                        panic!("Argument name passed to invoke_impl twice!")
+/// Arguments passed to the invoke_impl attribute, each documented with the syntax it is parsed from
+#[derive(Default)]
+struct InvokeImplArgs {
+    /// name("some_string"): name appended to the identifiers of generated code
+    name: Option<String>,
+    /// clone(2, 3): 0-indexed parameters (not counting the receiver) to clone rather than forward,
+    /// as the literals given
+    clones: Option<Vec<LitInt>>,
+    /// consumer_mode(all = "ref", enum = "owned"): how each invoke function passes results to its
+    /// consumer, keyed by the name of the invoke function without its invoke_ prefix; invoke
+    /// functions missing from it pass them by value
+    consumer_modes: HashMap<String, ConsumerMode>,
+    /// panic_conversions: whether to implement the panicking From<usize> for the enum
+    panic_conversions: bool,
+    /// skip("helper"): names of functions in the impl block to leave out of the generated code
+    skip: HashSet<String>,
+    /// include("fn1"): names of the only functions in the impl block to include in the generated
+    /// code, if given
+    include: Option<HashSet<String>>,
+    /// default_variant(fn1): variant the enum implements Default with, if given
+    default_variant: Option<Ident>,
+    /// parallel: whether to generate invoke_all_par and invoke_all_par_each
+    parallel: bool,
+    /// prefix("some_string"): prefix prepended to the names of the invoke functions
+    prefix: Option<String>,
+    /// verb("dispatch"): verb replacing invoke in the names of the invoke functions, if given
+    verb: Option<String>,
+    /// enum_name("SomeName"): name of the generated enum, replacing the default one
+    enum_name: Option<Ident>,
+    /// clone_self: whether to call methods taking self by value on clones of self
+    clone_self: bool,
+    /// vis("pub(crate)"): visibility of the generated items, replacing the default one
+    vis: Option<Visibility>,
+    /// free_fns: whether to generate a module of free functions delegating to the invoke functions
+    free_fns: bool,
+    /// on_invalid(skip), on_invalid(panic) or on_invalid(error): what the invoke functions
+    /// dispatching on usize indices do with indices out of range, if given
+    on_invalid: Option<InvalidIndexMode>,
+    /// rename(fn1 = "First"): variants replacing the names of functions in the enum, paired with
+    /// the functions they rename
+    renames: Vec<(Ident, Ident)>,
+    /// args_struct(Args): struct whose fields invoke_all_from passes to the functions, if given
+    args_struct: Option<syn::Path>,
+    /// repr(u8): unsigned integer type the enum is represented as, with an as_u8 accessor, if given
+    repr: Option<Ident>,
+    /// invoker("SomeInvoker"): name of the struct dispatching to the invoke functions, if given
+    invoker: Option<Ident>,
+}
+
+/// Helper function to parse the args passed into the attribute, separated by semicolons as in
+/// #[invoke_impl(name("some_string"); clone(2, 3))]. The arguments are documented on the fields
+/// of InvokeImplArgs. Returns an error spanning the offending part of the args if they are
+/// malformed.
+fn parse_args(args: TokenStream) -> Result<InvokeImplArgs, syn::Error> {
+    let punctuated_args = Punctuated::<Meta, syn::Token![;]>::parse_terminated.parse(args)?;
+    let mut result = InvokeImplArgs::default();
+    let mut consumer_mode_seen = false;
+    for arg in punctuated_args {
+        let arg_name = match arg.path().get_ident() {
+            Some(ident) => ident.to_string().to_lowercase(),
+            None => {
+                return Err(syn::Error::new_spanned(
+                    arg.path(),
+                    "invoke_impl arguments must be plain identifiers",
+                ))
+            }
+        };
+        match arg_name.as_str() {
+            "name" => {
+                let arg = expect_meta_list(arg, &arg_name)?;
+                if result.name.is_some() {
+                    return Err(passed_twice(&arg.path, &arg_name));
+                }
+                match arg.nested.first() {
+                    Some(NestedMeta::Lit(Lit::Str(litstr))) if arg.nested.len() == 1 => {
+                        result.name = Some(litstr.value())
                     }
-                    if arg.nested.len() != 1 {
-                        panic!("There can only be a single literal str argument to name!")
-                    } else {
-                        match &arg.nested[0] {
-                            NestedMeta::Meta(_) => {
-                                panic!("There can only be a single literal str argument to name!")
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            &arg.nested,
+                            "name takes a single string literal",
+                        ))
+                    }
+                }
+            }
+            "prefix" => {
+                let arg = expect_meta_list(arg, &arg_name)?;
+                if result.prefix.is_some() {
+                    return Err(passed_twice(&arg.path, &arg_name));
+                }
+                match arg.nested.first() {
+                    Some(NestedMeta::Lit(Lit::Str(litstr))) if arg.nested.len() == 1 => {
+                        result.prefix = Some(litstr.value())
+                    }
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            &arg.nested,
+                            "prefix takes a single string literal",
+                        ))
+                    }
+                }
+            }
+            "verb" => {
+                let arg = expect_meta_list(arg, &arg_name)?;
+                if result.verb.is_some() {
+                    return Err(passed_twice(&arg.path, &arg_name));
+                }
+                match arg.nested.first() {
+                    Some(NestedMeta::Lit(Lit::Str(litstr)))
+                        if arg.nested.len() == 1 && litstr.parse::<Ident>().is_ok() =>
+                    {
+                        result.verb = Some(litstr.value())
+                    }
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            &arg.nested,
+                            "verb takes a string literal holding an identifier",
+                        ))
+                    }
+                }
+            }
+            "clone" => {
+                let arg = expect_meta_list(arg, &arg_name)?;
+                if result.clones.is_some() {
+                    return Err(passed_twice(&arg.path, &arg_name));
+                }
+                let mut indices = vec![];
+                for nm in &arg.nested {
+                    match nm {
+                        NestedMeta::Lit(Lit::Int(litint)) => {
+                            litint.base10_parse::<usize>()?;
+                            indices.push(litint.clone());
+                        }
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                nm,
+                                "clone indices must be integer literals",
+                            ))
+                        }
+                    }
+                }
+                result.clones = Some(indices);
+            }
+            "consumer_mode" => {
+                let arg = expect_meta_list(arg, &arg_name)?;
+                if consumer_mode_seen {
+                    return Err(passed_twice(&arg.path, &arg_name));
+                }
+                consumer_mode_seen = true;
+                for nm in &arg.nested {
+                    let (key, litstr) = match nm {
+                        NestedMeta::Meta(Meta::NameValue(nv)) => {
+                            match (nv.path.get_ident(), &nv.lit) {
+                                (Some(key), Lit::Str(litstr)) => (key, litstr),
+                                _ => return Err(malformed_consumer_mode(nm)),
+                            }
+                        }
+                        _ => return Err(malformed_consumer_mode(nm)),
+                    };
+                    let key_name = key.to_string();
+                    if !CONSUMER_MODE_KEYS.contains(&key_name.as_str()) {
+                        return Err(syn::Error::new_spanned(
+                            key,
+                            format!(
+                                "consumer_mode can't be set for `{}`, only for: {}",
+                                key_name,
+                                CONSUMER_MODE_KEYS.join(", ")
+                            ),
+                        ));
+                    }
+                    let mode = match litstr.value().as_str() {
+                        "owned" => ConsumerMode::Owned,
+                        "ref" => ConsumerMode::Ref,
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                litstr,
+                                "consumer modes must be \"ref\" or \"owned\"",
+                            ))
+                        }
+                    };
+                    if result.consumer_modes.insert(key_name, mode).is_some() {
+                        return Err(syn::Error::new_spanned(
+                            key,
+                            format!("consumer_mode of `{}` is given twice", key),
+                        ));
+                    }
+                }
+            }
+            "skip" => {
+                let arg = expect_meta_list(arg, &arg_name)?;
+                if !result.skip.is_empty() {
+                    return Err(passed_twice(&arg.path, &arg_name));
+                }
+                result.skip = parse_function_names(&arg)?;
+            }
+            "include" => {
+                let arg = expect_meta_list(arg, &arg_name)?;
+                if result.include.is_some() {
+                    return Err(passed_twice(&arg.path, &arg_name));
+                }
+                result.include = Some(parse_function_names(&arg)?);
+            }
+            "default_variant" => {
+                let arg = expect_meta_list(arg, &arg_name)?;
+                if result.default_variant.is_some() {
+                    return Err(passed_twice(&arg.path, &arg_name));
+                }
+                match arg.nested.first() {
+                    Some(NestedMeta::Meta(Meta::Path(path)))
+                        if arg.nested.len() == 1 && path.get_ident().is_some() =>
+                    {
+                        result.default_variant = path.get_ident().cloned()
+                    }
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            &arg.nested,
+                            "default_variant takes the name of a single function",
+                        ))
+                    }
+                }
+            }
+            "enum_name" => {
+                let arg = expect_meta_list(arg, &arg_name)?;
+                if result.enum_name.is_some() {
+                    return Err(passed_twice(&arg.path, &arg_name));
+                }
+                match arg.nested.first() {
+                    Some(NestedMeta::Lit(Lit::Str(litstr))) if arg.nested.len() == 1 => {
+                        result.enum_name = Some(litstr.parse::<Ident>().map_err(|_| {
+                            syn::Error::new_spanned(litstr, "enum_name must be a valid identifier")
+                        })?)
+                    }
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            &arg.nested,
+                            "enum_name takes a single string literal",
+                        ))
+                    }
+                }
+            }
+            "invoker" => {
+                let arg = expect_meta_list(arg, &arg_name)?;
+                if result.invoker.is_some() {
+                    return Err(passed_twice(&arg.path, &arg_name));
+                }
+                match arg.nested.first() {
+                    Some(NestedMeta::Lit(Lit::Str(litstr))) if arg.nested.len() == 1 => {
+                        result.invoker = Some(litstr.parse::<Ident>().map_err(|_| {
+                            syn::Error::new_spanned(litstr, "invoker must be a valid identifier")
+                        })?)
+                    }
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            &arg.nested,
+                            "invoker takes a single string literal",
+                        ))
+                    }
+                }
+            }
+            "panic_conversions" => {
+                expect_flag(&arg, &arg_name)?;
+                if result.panic_conversions {
+                    return Err(passed_twice(arg.path(), &arg_name));
+                }
+                result.panic_conversions = true;
+            }
+            "parallel" => {
+                expect_flag(&arg, &arg_name)?;
+                if result.parallel {
+                    return Err(passed_twice(arg.path(), &arg_name));
+                }
+                result.parallel = true;
+            }
+            "vis" => {
+                let arg = expect_meta_list(arg, &arg_name)?;
+                if result.vis.is_some() {
+                    return Err(passed_twice(&arg.path, &arg_name));
+                }
+                match arg.nested.first() {
+                    Some(NestedMeta::Lit(Lit::Str(litstr))) if arg.nested.len() == 1 => {
+                        // The bare crate visibility is unstable, so it is rejected too
+                        match litstr.parse::<Visibility>() {
+                            Ok(vis) if !matches!(vis, Visibility::Crate(_)) => {
+                                result.vis = Some(vis)
                             }
-                            NestedMeta::Lit(lit) => {
-                                match lit {
-                                    Lit::Str(litstr) => result.0 = Some(litstr.value()),
-                                    _ => {
-                                        panic!("There can only be a single literal str argument to name!")
-                                    }
-                                }
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    litstr,
+                                    "vis must be a valid visibility, such as \"pub(crate)\"",
+                                ))
                             }
                         }
                     }
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            &arg.nested,
+                            "vis takes a single string literal",
+                        ))
+                    }
+                }
+            }
+            "on_invalid" => {
+                let arg = expect_meta_list(arg, &arg_name)?;
+                if result.on_invalid.is_some() {
+                    return Err(passed_twice(&arg.path, &arg_name));
                 }
-                "clone" => {
-                    if result.1.is_some() {
-                        panic!("Argument clone passed to invoke_impl twice!")
+                let mode = match arg.nested.first() {
+                    Some(NestedMeta::Meta(Meta::Path(path))) if arg.nested.len() == 1 => {
+                        match path.get_ident().map(|ident| ident.to_string()).as_deref() {
+                            Some("skip") => Some(InvalidIndexMode::Skip),
+                            Some("panic") => Some(InvalidIndexMode::Panic),
+                            Some("error") => Some(InvalidIndexMode::Error),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+                match mode {
+                    Some(mode) => result.on_invalid = Some(mode),
+                    None => {
+                        return Err(syn::Error::new_spanned(
+                            &arg.nested,
+                            "on_invalid takes one of skip, panic or error",
+                        ))
                     }
-                    let mut indices = HashSet::new();
-                    for nm in &arg.nested {
-                        match nm {
-                            NestedMeta::Meta(_) => {
-                                panic!("Arguments to clone must be literal ints!")
+                }
+            }
+            "rename" => {
+                let arg = expect_meta_list(arg, &arg_name)?;
+                if !result.renames.is_empty() {
+                    return Err(passed_twice(&arg.path, &arg_name));
+                }
+                for nm in &arg.nested {
+                    let (key, litstr) = match nm {
+                        NestedMeta::Meta(Meta::NameValue(nv)) => {
+                            match (nv.path.get_ident(), &nv.lit) {
+                                (Some(key), Lit::Str(litstr)) => (key, litstr),
+                                _ => return Err(malformed_rename(nm)),
                             }
-                            NestedMeta::Lit(lit) => match lit {
-                                Lit::Int(litint) => {
-                                    indices
-                                        .insert(litint.base10_digits().parse::<usize>().unwrap());
-                                }
-                                _ => {
-                                    panic!("Arguments to clone must be literal ints!")
-                                }
-                            },
                         }
+                        _ => return Err(malformed_rename(nm)),
+                    };
+                    let variant = litstr.parse::<Ident>().map_err(|_| {
+                        syn::Error::new_spanned(litstr, "rename targets must be valid identifiers")
+                    })?;
+                    if result.renames.iter().any(|(renamed, _)| renamed == key) {
+                        return Err(syn::Error::new_spanned(
+                            key,
+                            format!("`{}` is renamed twice", key),
+                        ));
+                    }
+                    result.renames.push((key.clone(), variant));
+                }
+            }
+            "args_struct" => {
+                let arg = expect_meta_list(arg, &arg_name)?;
+                if result.args_struct.is_some() {
+                    return Err(passed_twice(&arg.path, &arg_name));
+                }
+                match arg.nested.first() {
+                    Some(NestedMeta::Meta(Meta::Path(path))) if arg.nested.len() == 1 => {
+                        result.args_struct = Some(path.clone())
+                    }
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            &arg.nested,
+                            "args_struct takes the path of a single struct",
+                        ))
+                    }
+                }
+            }
+            "repr" => {
+                let arg = expect_meta_list(arg, &arg_name)?;
+                if result.repr.is_some() {
+                    return Err(passed_twice(&arg.path, &arg_name));
+                }
+                match arg.nested.first() {
+                    Some(NestedMeta::Meta(Meta::Path(path)))
+                        if arg.nested.len() == 1
+                            && path
+                                .get_ident()
+                                .is_some_and(|ident| repr_max(ident).is_some()) =>
+                    {
+                        result.repr = path.get_ident().cloned()
                     }
-                    result.1 = Some(indices);
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            &arg.nested,
+                            "repr takes one of u8, u16, u32 or u64",
+                        ))
+                    }
+                }
+            }
+            "free_fns" => {
+                expect_flag(&arg, &arg_name)?;
+                if result.free_fns {
+                    return Err(passed_twice(arg.path(), &arg_name));
                 }
-                _ => {
-                    panic!("The only valid arguments to invoke_impl are name and clone!")
+                result.free_fns = true;
+            }
+            "clone_self" => {
+                expect_flag(&arg, &arg_name)?;
+                if result.clone_self {
+                    return Err(passed_twice(arg.path(), &arg_name));
                 }
+                result.clone_self = true;
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    arg.path(),
+                    "the only valid arguments to invoke_impl are name, clone, consumer_mode, \
+                    panic_conversions, skip, include, default_variant, parallel, prefix, verb, \
+                    enum_name, clone_self, vis, free_fns, on_invalid, rename, args_struct, repr \
+                    and invoker",
+                ))
             }
         }
-        result
+    }
+    Ok(result)
+}
+
+/// Helper function to unwrap an argument to the attribute that takes a list of values, as in
+/// name("some_string")
+fn expect_meta_list(arg: Meta, arg_name: &str) -> Result<MetaList, syn::Error> {
+    match arg {
+        Meta::List(list) => Ok(list),
+        _ => Err(syn::Error::new_spanned(
+            arg,
+            format!("{} expects its values in parentheses", arg_name),
+        )),
+    }
+}
+
+/// Helper function to check that an argument to the attribute is a bare flag, as in
+/// panic_conversions
+fn expect_flag(arg: &Meta, arg_name: &str) -> Result<(), syn::Error> {
+    match arg {
+        Meta::Path(_) => Ok(()),
+        _ => Err(syn::Error::new_spanned(
+            arg,
+            format!("{} is a flag and doesn't take any values", arg_name),
+        )),
+    }
+}
+
+/// Helper function to parse the string literals naming functions passed to skip or include
+fn parse_function_names(arg: &MetaList) -> Result<HashSet<String>, syn::Error> {
+    arg.nested
+        .iter()
+        .map(|nm| match nm {
+            NestedMeta::Lit(Lit::Str(litstr)) => Ok(litstr.value()),
+            _ => Err(syn::Error::new_spanned(
+                nm,
+                "functions must be named by string literals",
+            )),
+        })
+        .collect()
+}
+
+/// Helper function to create the error for an argument passed to the attribute twice
+fn passed_twice(path: &syn::Path, arg_name: &str) -> syn::Error {
+    syn::Error::new_spanned(
+        path,
+        format!("argument {} is passed to invoke_impl twice", arg_name),
+    )
+}
+
+/// Helper function to create the error for a malformed consumer_mode entry
+fn malformed_consumer_mode(nm: &NestedMeta) -> syn::Error {
+    syn::Error::new_spanned(
+        nm,
+        "consumer modes must be given as invoke_function = \"ref\" or \"owned\"",
+    )
+}
+
+/// Helper function to get the largest value of the unsigned integer type named by repr, or None if
+/// it isn't one of the types the enum can be represented as
+fn repr_max(repr: &Ident) -> Option<u64> {
+    match repr.to_string().as_str() {
+        "u8" => Some(u8::MAX.into()),
+        "u16" => Some(u16::MAX.into()),
+        "u32" => Some(u32::MAX.into()),
+        "u64" => Some(u64::MAX),
+        _ => None,
+    }
+}
+
+/// Helper function to create the error for a malformed rename entry
+fn malformed_rename(nm: &NestedMeta) -> syn::Error {
+    syn::Error::new_spanned(nm, "renames must be given as function = \"Variant\"")
+}
+
+/// Names (without their invoke_ prefix) of the invoke functions whose consumer mode can be set,
+/// i.e. those passing results to a consumer closure.
+const CONSUMER_MODE_KEYS: [&str; 19] = [
+    "all",
+    "all_rev",
+    "all_then",
+    "all_progress",
+    "subset",
+    "subset_unique",
+    "subset_checked",
+    "all_enumerated",
+    "all_enum",
+    "enumerated",
+    "enum",
+    "by_name",
+    "subset_lazy",
+    "all_until",
+    "all_enumerated_until",
+    "all_try",
+    "all_async",
+    "all_join",
+    "all_par_each",
+];
+
+/// Helper function to get the key an invoke function's consumer mode is set under, which is its
+/// name without the invoke_ prefix
+fn generate_consumer_mode_key(invoke_type: InvokeType) -> &'static str {
+    generate_invoke_base_name(invoke_type)
+        .strip_prefix("invoke_")
+        .unwrap()
+}
+
+/// Helper function to generate the correct Ident for an invoke function signature, with invoke
+/// replaced by the verb (if any), the prefix (if any) prepended and the name (if any) appended
+fn generate_invoke_name(
+    name: &Option<String>,
+    prefix: &Option<String>,
+    verb: &Option<String>,
+    invoke_type: InvokeType,
+) -> Ident {
+    let base_string = match verb {
+        Some(verb_s) => generate_invoke_base_name(invoke_type).replacen("invoke", verb_s, 1),
+        None => generate_invoke_base_name(invoke_type).to_string(),
+    };
+    let prefixed = if let Some(prefix_s) = prefix {
+        format!("{}_{}", prefix_s, base_string)
     } else {
-        panic!(
-            "invoke_impl currently only supports args name and clone in the format \
-        #[invoke-impl(name(\"name\"); clone(2, 3, 4)], and more than two args were passed in!"
-        );
+        base_string
+    };
+    if let Some(name_s) = name {
+        format_ident!("{}_{}", prefixed, name_s)
+    } else {
+        format_ident!("{}", prefixed)
+    }
+}
+
+/// Helper function to generate the name of invoke_all_from, which the name, prefix and verb
+/// arguments change like the names of the invoke functions
+fn generate_invoke_all_from_name(
+    name: &Option<String>,
+    prefix: &Option<String>,
+    verb: &Option<String>,
+) -> Ident {
+    let base_string = match verb {
+        Some(verb_s) => format!("{}_all_from", verb_s),
+        None => "invoke_all_from".to_string(),
+    };
+    let prefixed = match prefix {
+        Some(prefix_s) => format!("{}_{}", prefix_s, base_string),
+        None => base_string,
+    };
+    match name {
+        Some(name_s) => format_ident!("{}_{}", prefixed, name_s),
+        None => format_ident!("{}", prefixed),
+    }
+}
+
+/// Helper function to check whether an invoke function uses std, typically for its collections, so
+/// that it is only generated with the std feature enabled
+fn requires_std(invoke_type: InvokeType) -> bool {
+    matches!(
+        invoke_type,
+        InvokeType::ByName
+            | InvokeType::UniqueSubset
+            | InvokeType::Collect
+            | InvokeType::EnumeratedCollect
+            | InvokeType::Deque
+            | InvokeType::NamedMap
+            | InvokeType::CollectIndices
+            | InvokeType::Results
+            | InvokeType::EnumCollect
+            | InvokeType::Partition
+            | InvokeType::Fastest
+            | InvokeType::FirstNonPanicking
+            | InvokeType::Iter
+            | InvokeType::Par
+            | InvokeType::ParEach
+    )
+}
+
+/// Helper function to get the doc comment of an invoke function, with the leading space a /// doc
+/// comment has
+fn generate_invoke_doc(invoke_type: InvokeType) -> &'static str {
+    match invoke_type {
+        InvokeType::Specified(specifier) => match specifier {
+            SpecificationType::Enum => {
+                " Invokes the functions designated by the given enum variants, in the order given."
+            }
+            SpecificationType::Enumerated => {
+                " Invokes the functions at the given indices of the impl block, in the order given."
+            }
+        },
+        InvokeType::SpecifiedAll(specifier) => match specifier {
+            SpecificationType::Enum => {
+                " Invokes every function of the impl block in order, along with its enum variant."
+            }
+            SpecificationType::Enumerated => {
+                " Invokes every function of the impl block in order, along with its index."
+            }
+        },
+        InvokeType::ByName => {
+            " Invokes the functions with the given names in the order given, or none of them if a \
+             name is unknown, which is returned as the error."
+        }
+        InvokeType::All => " Invokes every function of the impl block in order.",
+        InvokeType::AllRev => " Invokes every function of the impl block in reverse order.",
+        InvokeType::AllThen => {
+            " Invokes every function of the impl block in order, then calls \
+             invoke_impl_finish once."
+        }
+        InvokeType::Progress => {
+            " Invokes every function of the impl block in order, reporting the number of functions \
+             completed and the total to invoke_impl_progress after each one."
+        }
+        InvokeType::Subset => {
+            " Invokes the functions at the given indices of the impl block, in the order given."
+        }
+        InvokeType::UniqueSubset => {
+            " Invokes the functions at the given indices of the impl block, in the order given, \
+             skipping indices given before."
+        }
+        InvokeType::CheckedSubset => {
+            " Invokes the functions at the given indices of the impl block, in the order given, \
+             until an index is out of range, which is returned as the error."
+        }
+        InvokeType::Tuple => {
+            " Invokes every function of the impl block in order, returning the results as a tuple."
+        }
+        InvokeType::Batch => {
+            " Invokes every function of the impl block in order, then passes all the results to \
+             the consumer at once."
+        }
+        InvokeType::Collect => {
+            " Invokes every function of the impl block in order, returning the results as a Vec."
+        }
+        InvokeType::EnumeratedCollect => {
+            " Invokes every function of the impl block in order, returning the results paired with \
+             the indices of their functions as a Vec."
+        }
+        InvokeType::Deque => {
+            " Invokes every function of the impl block in order, returning the results as a \
+             VecDeque."
+        }
+        InvokeType::NamedMap => {
+            " Invokes every function of the impl block, returning the results keyed by the names \
+             of their functions."
+        }
+        InvokeType::CollectIndices => {
+            " Invokes every function of the impl block in order, returning the results of those at \
+             the indices in invoke_impl_indices."
+        }
+        InvokeType::Results => {
+            " Invokes every function of the impl block in order, returning the results indexable \
+             by enum variant or index."
+        }
+        InvokeType::EnumCollect => {
+            " Invokes the functions designated by the given enum variants, returning the results \
+             paired with their variants."
+        }
+        InvokeType::Partition => {
+            " Invokes every function of the impl block in order, grouping the results, along with \
+             their indices, by the key invoke_impl_classify gives them."
+        }
+        InvokeType::Extend => {
+            " Invokes every function of the impl block in order, extending \
+             invoke_impl_target with the results."
+        }
+        InvokeType::Into => {
+            " Invokes every function of the impl block in order, writing each result into the \
+             slot of invoke_impl_out at the index of its function."
+        }
+        InvokeType::Fold => {
+            " Invokes every function of the impl block in order, folding the results into \
+             invoke_impl_init."
+        }
+        InvokeType::EnumFold => {
+            " Invokes every function of the impl block in order, folding the results, along with \
+             their enum variants, into invoke_impl_init."
+        }
+        InvokeType::With => {
+            " Invokes every function of the impl block in order, passing invoke_impl_acc \
+             along with each result to the consumer."
+        }
+        InvokeType::LazySubset => {
+            " Invokes the functions at the given indices of the impl block, building the \
+             arguments of each with the factory."
+        }
+        InvokeType::Fastest => {
+            " Invokes and times every function of the impl block, returning the variant, duration \
+             and result of the fastest one."
+        }
+        InvokeType::FirstNonPanicking => {
+            " Invokes the functions of the impl block in order until one doesn't panic, returning \
+             its result."
+        }
+        InvokeType::FirstSome => {
+            " Invokes the functions of the impl block in order until one returns Some, returning \
+             it."
+        }
+        InvokeType::Until => {
+            " Invokes the functions of the impl block in order until the consumer breaks."
+        }
+        InvokeType::EnumeratedUntil => {
+            " Invokes the functions of the impl block in order, along with their indices, until \
+             the consumer breaks."
+        }
+        InvokeType::Try => {
+            " Invokes the functions of the impl block in order until one returns Err, returning \
+             it."
+        }
+        InvokeType::CountOk => {
+            " Invokes every function of the impl block in order, returning how many returned Ok."
+        }
+        InvokeType::AllAsync => " Invokes and awaits every function of the impl block in order.",
+        InvokeType::AllJoin => {
+            " Invokes every function of the impl block, awaiting them all concurrently."
+        }
+        InvokeType::Par => {
+            " Invokes every function of the impl block in parallel, returning the results in \
+             impl block order."
+        }
+        InvokeType::ParEach => {
+            " Invokes every function of the impl block in parallel, passing each result to the \
+             consumer as soon as it is ready."
+        }
+        InvokeType::Iter => {
+            " Returns an iterator lazily invoking every function of the impl block in order."
+        }
     }
 }
 
-/// Helper function to generate the correct Ident for an invoke function signature
-fn generate_invoke_name(name: &Option<String>, invoke_type: InvokeType) -> Ident {
-    let base_string = match invoke_type {
+/// Helper function to get the name of an invoke function, before any name argument is appended
+fn generate_invoke_base_name(invoke_type: InvokeType) -> &'static str {
+    match invoke_type {
         InvokeType::Specified(specifier) => match specifier {
             SpecificationType::Enum => "invoke_enum",
             SpecificationType::Enumerated => "invoke_enumerated",
@@ -1133,25 +6156,203 @@ fn generate_invoke_name(name: &Option<String>, invoke_type: InvokeType) -> Ident
             SpecificationType::Enum => "invoke_all_enum",
             SpecificationType::Enumerated => "invoke_all_enumerated",
         },
+        InvokeType::ByName => "invoke_by_name",
         InvokeType::All => "invoke_all",
+        InvokeType::AllRev => "invoke_all_rev",
+        InvokeType::AllThen => "invoke_all_then",
+        InvokeType::Progress => "invoke_all_progress",
         InvokeType::Subset => "invoke_subset",
-    };
-    if let Some(name_s) = name {
-        format_ident!("{}_{}", base_string, name_s)
+        InvokeType::UniqueSubset => "invoke_subset_unique",
+        InvokeType::CheckedSubset => "invoke_subset_checked",
+        InvokeType::Tuple => "invoke_all_tuple",
+        InvokeType::Batch => "invoke_all_batch",
+        InvokeType::Collect => "invoke_all_collect",
+        InvokeType::EnumeratedCollect => "invoke_all_enumerated_collect",
+        InvokeType::Deque => "invoke_all_deque",
+        InvokeType::NamedMap => "invoke_all_named_map",
+        InvokeType::CollectIndices => "invoke_all_collect_indices",
+        InvokeType::Results => "invoke_all_results",
+        InvokeType::EnumCollect => "invoke_enum_collect",
+        InvokeType::Partition => "invoke_all_partition",
+        InvokeType::Extend => "invoke_all_extend",
+        InvokeType::Into => "invoke_all_into",
+        InvokeType::Fold => "invoke_all_fold",
+        InvokeType::EnumFold => "invoke_all_enum_fold",
+        InvokeType::With => "invoke_all_with",
+        InvokeType::LazySubset => "invoke_subset_lazy",
+        InvokeType::Fastest => "invoke_all_fastest",
+        InvokeType::FirstNonPanicking => "invoke_first_non_panicking",
+        InvokeType::FirstSome => "invoke_all_first_some",
+        InvokeType::Until => "invoke_all_until",
+        InvokeType::EnumeratedUntil => "invoke_all_enumerated_until",
+        InvokeType::Try => "invoke_all_try",
+        InvokeType::CountOk => "invoke_all_count_ok",
+        InvokeType::AllAsync => "invoke_all_async",
+        InvokeType::AllJoin => "invoke_all_join",
+        InvokeType::Par => "invoke_all_par",
+        InvokeType::ParEach => "invoke_all_par_each",
+        InvokeType::Iter => "invoke_all_iter",
+    }
+}
+
+/// Helper function to generate the name of the associated enum: the enum_name argument if given,
+/// and otherwise the PascalCase concatenation of the struct identifier, InvokeImpl and the name
+fn generate_enum_name(
+    struct_ident: &Ident,
+    name: &Option<String>,
+    enum_name: &Option<Ident>,
+) -> Ident {
+    if let Some(enum_name) = enum_name {
+        enum_name.clone()
+    } else if let Some(n) = name {
+        format_ident!("{}InvokeImpl{}", struct_ident, to_pascal_case(n))
+    } else {
+        format_ident!("{}InvokeImpl", struct_ident)
+    }
+}
+
+/// Helper function to convert a PascalCase string to snake_case
+fn to_snake_case(s: &str) -> String {
+    let mut snake = String::new();
+    let mut previous_lower = false;
+    for c in s.chars() {
+        if c.is_uppercase() && previous_lower {
+            snake.push('_');
+        }
+        previous_lower = c.is_lowercase() || c.is_ascii_digit();
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+/// Helper function to convert a snake_case (or SCREAMING_SNAKE_CASE) string to PascalCase
+fn to_pascal_case(s: &str) -> String {
+    s.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first
+                    .to_uppercase()
+                    .chain(chars.flat_map(char::to_lowercase))
+                    .collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Helper function to generate the name of the iterator struct returned by invoke_all_iter, in the
+/// same PascalCase form as the default enum name
+fn generate_iter_name(struct_ident: &Ident, name: &Option<String>) -> Ident {
+    if let Some(n) = name {
+        format_ident!("{}InvokeImplIter{}", struct_ident, to_pascal_case(n))
     } else {
-        format_ident!("{}", base_string)
+        format_ident!("{}InvokeImplIter", struct_ident)
     }
 }
 
-/// Helper function to generate the name of the associated enum
-fn generate_enum_name(struct_ident: &Ident, name: &Option<String>) -> Ident {
+/// Helper function to generate the name of the struct returned by invoke_all_results, in the same
+/// PascalCase form as the default enum name
+fn generate_results_name(struct_ident: &Ident, name: &Option<String>) -> Ident {
     if let Some(n) = name {
-        format_ident!("{}_invoke_impl_enum_{}", struct_ident, n)
+        format_ident!("{}Results{}", struct_ident, to_pascal_case(n))
     } else {
-        format_ident!("{}_invoke_impl_enum", struct_ident)
+        format_ident!("{}Results", struct_ident)
+    }
+}
+
+/// Helper function to get the type of the results of the functions in impl block, which is () if
+/// they don't declare a return type
+fn get_result_type(output_type: &ReturnType) -> Type {
+    match output_type {
+        ReturnType::Type(_, bx) => *bx.clone(),
+        ReturnType::Default => syn::parse(quote!(()).into()).unwrap(),
+    }
+}
+
+/// Helper function to get the name of a return type as written in source, for error messages: ()
+/// if there is none, and otherwise the type without the spaces the token stream puts between
+/// tokens, except between words and after commas, semicolons and arrows (e.g. Vec<(u8, &'a str)>)
+fn return_type_name(output: &ReturnType) -> String {
+    let ty = match output {
+        ReturnType::Default => return "()".to_string(),
+        ReturnType::Type(_, ty) => ty.to_token_stream().to_string(),
+    };
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut name = String::new();
+    for token in ty.split(' ') {
+        let spaced = match (name.chars().last(), token.chars().next()) {
+            (Some(prev), Some(next)) => {
+                (is_word(prev) && (is_word(next) || next == '\''))
+                    || matches!(prev, ',' | ';')
+                    || name.ends_with("->")
+                    || token.starts_with("->")
+            }
+            _ => false,
+        };
+        if spaced {
+            name.push(' ');
+        }
+        name.push_str(token);
+    }
+    name
+}
+
+/// Helper function to split a return type of the form Result<T, E> into T and E. Returns None for
+/// any other return type, including aliases of Result that take a single parameter.
+fn split_result_type(output_type: &ReturnType) -> Option<(Type, Type)> {
+    let result_type = match output_type {
+        ReturnType::Type(_, bx) => bx,
+        ReturnType::Default => return None,
+    };
+    let segment = match **result_type {
+        Type::Path(ref tp) if tp.qself.is_none() => tp.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != "Result" {
+        return None;
+    }
+    match segment.arguments {
+        PathArguments::AngleBracketed(ref args) if args.args.len() == 2 => {
+            match (&args.args[0], &args.args[1]) {
+                (GenericArgument::Type(ok), GenericArgument::Type(err)) => {
+                    Some((ok.clone(), err.clone()))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Helper function to get T out of a return type of the form Option<T>. Returns None for any other
+/// return type.
+fn split_option_type(output_type: &ReturnType) -> Option<Type> {
+    let option_type = match output_type {
+        ReturnType::Type(_, bx) => bx,
+        ReturnType::Default => return None,
+    };
+    let segment = match **option_type {
+        Type::Path(ref tp) if tp.qself.is_none() => tp.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != "Option" {
+        return None;
+    }
+    match segment.arguments {
+        PathArguments::AngleBracketed(ref args) if args.args.len() == 1 => match args.args[0] {
+            GenericArgument::Type(ref some) => Some(some.clone()),
+            _ => None,
+        },
+        _ => None,
     }
 }
 
+/// Helper function to check whether the functions in impl block return something other than ()
+fn has_return_type(output_type: &ReturnType) -> bool {
+    output_type != &generate_trailing_return_type() && output_type != &ReturnType::Default
+}
+
 /// Helper function to generate return type -> (), since this parses differently than having no
 /// return type at all
 fn generate_trailing_return_type() -> ReturnType {
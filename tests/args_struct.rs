@@ -0,0 +1,65 @@
+use invoke_impl::invoke_impl;
+
+struct SolveArgs {
+    tolerance: f64,
+    label: String,
+    steps: u32,
+}
+
+struct Solvers {
+    scale: u32,
+}
+
+#[invoke_impl(clone(1); args_struct(SolveArgs))]
+impl Solvers {
+    pub fn bisection(&self, steps: u32, label: String, tolerance: &f64) -> String {
+        format!("{}:{}:{}", label, steps * self.scale, tolerance)
+    }
+
+    pub fn newton(&self, steps: u32, label: String, tolerance: &f64) -> String {
+        format!("{}:{}:{}", label, steps + self.scale, tolerance)
+    }
+}
+
+#[test]
+fn invoke_all_from_maps_fields_to_parameters() {
+    let solvers = Solvers { scale: 3 };
+    let args = SolveArgs {
+        tolerance: 0.5,
+        label: String::from("run"),
+        steps: 4,
+    };
+
+    let mut results = vec![];
+    solvers.invoke_all_from(&args, |r| results.push(r));
+    assert_eq!(results, vec!["run:12:0.5", "run:7:0.5"]);
+
+    // The arguments are only borrowed, so they can be reused
+    let mut results = vec![];
+    solvers.invoke_all_from(&args, |r| results.push(r));
+    assert_eq!(results.len(), 2);
+    assert_eq!(args.label, "run");
+}
+
+struct Counters;
+
+#[invoke_impl(args_struct(CounterArgs); name("counted"))]
+impl Counters {
+    pub fn add(a: u8, b: u8) {
+        let _ = a + b;
+    }
+
+    pub fn sub(a: u8, b: u8) {
+        let _ = a.wrapping_sub(b);
+    }
+}
+
+struct CounterArgs {
+    b: u8,
+    a: u8,
+}
+
+#[test]
+fn invoke_all_from_without_results() {
+    Counters::invoke_all_from_counted(&CounterArgs { b: 1, a: 2 });
+}
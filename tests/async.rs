@@ -0,0 +1,29 @@
+use invoke_impl::invoke_impl;
+
+struct Fetchers {
+    base: u32,
+}
+
+#[invoke_impl]
+impl Fetchers {
+    pub async fn first(&self, i: u32) -> u32 {
+        self.base + i
+    }
+
+    pub async fn second(&self, i: u32) -> u32 {
+        tokio::task::yield_now().await;
+        self.base + i * 2
+    }
+
+    pub async fn third(&self, i: u32) -> u32 {
+        self.base + i * 3
+    }
+}
+
+#[tokio::test]
+async fn invoke_all_async_awaits_in_order() {
+    let fetchers = Fetchers { base: 10 };
+    let mut results = vec![];
+    fetchers.invoke_all_async(1, |r| results.push(r)).await;
+    assert_eq!(results, vec![11, 12, 13]);
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "std")]
+
+use invoke_impl::invoke_impl;
+
+trait Greeter {
+    fn greet(&self) -> String;
+}
+
+struct English(String);
+struct French(String);
+
+impl Greeter for English {
+    fn greet(&self) -> String {
+        format!("Hello, {}", self.0)
+    }
+}
+
+impl Greeter for French {
+    fn greet(&self) -> String {
+        format!("Bonjour, {}", self.0)
+    }
+}
+
+struct Greeters;
+
+#[invoke_impl(clone(0); consumer_mode(all_enum = "ref"))]
+impl Greeters {
+    pub fn english(name: String) -> Box<dyn Greeter> {
+        Box::new(English(name))
+    }
+
+    pub fn french(name: String) -> Box<dyn Greeter> {
+        Box::new(French(name))
+    }
+}
+
+fn name() -> String {
+    String::from("Ada")
+}
+
+#[test]
+fn boxed_results_are_collected() {
+    let greetings = Greeters::invoke_all_collect(name())
+        .iter()
+        .map(|greeter| greeter.greet())
+        .collect::<Vec<_>>();
+    assert_eq!(greetings, vec!["Hello, Ada", "Bonjour, Ada"]);
+}
+
+#[test]
+fn boxed_results_are_consumed() {
+    let mut greeters: Vec<Box<dyn Greeter>> = vec![];
+    Greeters::invoke_all(name(), |greeter| greeters.push(greeter));
+    assert_eq!(greeters[1].greet(), "Bonjour, Ada");
+
+    let mut greetings = vec![];
+    Greeters::invoke_all_enum(name(), |variant, greeter| {
+        greetings.push((variant, greeter.greet()))
+    });
+    assert_eq!(
+        greetings[0],
+        (GreetersInvokeImpl::english, String::from("Hello, Ada"))
+    );
+
+    let mut greetings = vec![];
+    Greeters::invoke_subset(
+        name(),
+        |greeter| greetings.push(greeter.greet()),
+        vec![1].into_iter(),
+    );
+    assert_eq!(greetings, vec!["Bonjour, Ada"]);
+
+    let (english, french) = Greeters::invoke_all_tuple(name());
+    assert_eq!(english.greet() + &french.greet(), "Hello, AdaBonjour, Ada");
+
+    Greeters::invoke_all_batch(name(), |greeters| assert_eq!(greeters.len(), 2));
+    assert_eq!(
+        Greeters::invoke_all_iter(name()).last().unwrap().greet(),
+        "Bonjour, Ada"
+    );
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "catch_unwind")]
+
+use invoke_impl::invoke_impl;
+
+struct Parsers;
+
+#[invoke_impl(clone(0))]
+impl Parsers {
+    pub fn strict(input: String) -> u32 {
+        input.parse().expect("strict parser only takes digits")
+    }
+
+    pub fn lenient(input: String) -> u32 {
+        input
+            .chars()
+            .filter(char::is_ascii_digit)
+            .collect::<String>()
+            .parse()
+            .unwrap()
+    }
+}
+
+#[test]
+fn invoke_first_non_panicking_skips_panicking_functions() {
+    assert_eq!(
+        Parsers::invoke_first_non_panicking(String::from("42")),
+        Some(42)
+    );
+    assert_eq!(
+        Parsers::invoke_first_non_panicking(String::from("4x2")),
+        Some(42)
+    );
+    assert_eq!(
+        Parsers::invoke_first_non_panicking(String::from("none")),
+        None
+    );
+}
@@ -0,0 +1,101 @@
+use invoke_impl::invoke_impl;
+
+struct Backends;
+
+#[invoke_impl(default_variant(accelerated))]
+impl Backends {
+    pub fn portable(x: u32) -> u32 {
+        x
+    }
+
+    #[cfg(feature = "timing")]
+    pub fn accelerated(x: u32) -> u32 {
+        x * 2
+    }
+
+    #[cfg(not(feature = "timing"))]
+    pub fn fallback(x: u32) -> u32 {
+        x * 3
+    }
+
+    pub fn reference(x: u32) -> u32 {
+        x * 4
+    }
+}
+
+#[test]
+fn gated_functions_compile_out() {
+    let mut results = vec![];
+    Backends::invoke_all(1, |r| results.push(r));
+    if cfg!(feature = "timing") {
+        assert_eq!(results, vec![1, 2, 4]);
+    } else {
+        assert_eq!(results, vec![1, 3, 4]);
+    }
+    #[cfg(feature = "std")]
+    assert_eq!(Backends::invoke_all_collect(1), results);
+    assert_eq!(BackendsInvokeImpl::iter().count(), 3);
+    assert_eq!(BackendsInvokeImpl::VARIANTS.len(), 3);
+}
+
+#[test]
+fn indices_are_positions_in_the_impl_block() {
+    // Whichever of accelerated and fallback is compiled, the other leaves a gap in the indices
+    let mut indices = vec![];
+    Backends::invoke_all_enumerated(1, |i, _| indices.push(i));
+    let mut variants = vec![];
+    Backends::invoke_all_enum(1, |e, _| variants.push(usize::from(e)));
+    assert_eq!(indices, variants);
+    if cfg!(feature = "timing") {
+        assert_eq!(indices, vec![0, 1, 3]);
+        assert!(BackendsInvokeImpl::try_from(2).is_err());
+    } else {
+        assert_eq!(indices, vec![0, 2, 3]);
+        assert!(BackendsInvokeImpl::try_from(1).is_err());
+    }
+    assert!(matches!(
+        BackendsInvokeImpl::try_from(3),
+        Ok(BackendsInvokeImpl::reference)
+    ));
+
+    let mut results = vec![];
+    Backends::invoke_enumerated(2, |_, r| results.push(r), vec![3, 0].into_iter());
+    assert_eq!(results, vec![8, 2]);
+}
+
+#[cfg(feature = "timing")]
+#[test]
+fn default_variant_follows_its_function() {
+    assert!(matches!(
+        BackendsInvokeImpl::default(),
+        BackendsInvokeImpl::accelerated
+    ));
+}
+
+#[test]
+fn compiled_consts_only_count_compiled_functions() {
+    assert_eq!(Backends::METHOD_COUNT, 4);
+    assert_eq!(Backends::COMPILED_METHOD_COUNT, 3);
+    if cfg!(feature = "timing") {
+        assert_eq!(
+            Backends::COMPILED_METHOD_NAMES,
+            ["portable", "accelerated", "reference"]
+        );
+    } else {
+        assert_eq!(
+            Backends::COMPILED_METHOD_NAMES,
+            ["portable", "fallback", "reference"]
+        );
+    }
+    assert_eq!(
+        Backends::COMPILED_METHOD_COUNT,
+        BackendsInvokeImpl::VARIANTS.len()
+    );
+}
+
+#[test]
+fn enum_count_includes_gated_functions_unlike_variants() {
+    assert_eq!(BackendsInvokeImpl::COUNT, Backends::METHOD_COUNT);
+    assert_eq!(BackendsInvokeImpl::NAMES.len(), 4);
+    assert_eq!(BackendsInvokeImpl::VARIANTS.len(), 3);
+}
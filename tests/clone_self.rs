@@ -0,0 +1,71 @@
+use invoke_impl::invoke_impl;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Counts how often it is cloned
+struct Request {
+    path: String,
+    clones: Rc<Cell<usize>>,
+}
+
+impl Clone for Request {
+    fn clone(&self) -> Self {
+        self.clones.set(self.clones.get() + 1);
+        Request {
+            path: self.path.clone(),
+            clones: Rc::clone(&self.clones),
+        }
+    }
+}
+
+#[invoke_impl(clone_self)]
+impl Request {
+    pub fn with_json(mut self) -> String {
+        self.path.push_str(".json");
+        self.path
+    }
+
+    pub fn with_xml(mut self) -> String {
+        self.path.push_str(".xml");
+        self.path
+    }
+
+    pub fn with_csv(mut self) -> String {
+        self.path.push_str(".csv");
+        self.path
+    }
+}
+
+fn request(clones: &Rc<Cell<usize>>) -> Request {
+    Request {
+        path: String::from("/report"),
+        clones: Rc::clone(clones),
+    }
+}
+
+#[test]
+fn invoke_all_moves_self_into_last_call() {
+    let clones = Rc::new(Cell::new(0));
+    let mut paths = vec![];
+    request(&clones).invoke_all(|path| paths.push(path));
+    assert_eq!(paths, vec!["/report.json", "/report.xml", "/report.csv"]);
+    assert_eq!(clones.get(), 2);
+
+    #[cfg(feature = "std")]
+    {
+        assert_eq!(
+            request(&clones).invoke_all_collect(),
+            vec!["/report.json", "/report.xml", "/report.csv"]
+        );
+        assert_eq!(clones.get(), 4);
+    }
+}
+
+#[test]
+fn invoke_subset_clones_self_for_every_call() {
+    let clones = Rc::new(Cell::new(0));
+    let mut paths = vec![];
+    request(&clones).invoke_subset(|path| paths.push(path), vec![2, 0].into_iter());
+    assert_eq!(paths, vec!["/report.csv", "/report.json"]);
+    assert_eq!(clones.get(), 2);
+}
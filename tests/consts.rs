@@ -0,0 +1,78 @@
+use invoke_impl::invoke_impl;
+
+struct Empty;
+
+#[invoke_impl]
+impl Empty {
+    pub const UNRELATED: i32 = 0;
+}
+
+#[test]
+fn empty_impl_has_no_methods() {
+    assert_eq!(Empty::METHOD_COUNT, 0);
+    assert!(Empty::METHOD_LIST.is_empty());
+    assert_eq!(Empty::UNRELATED, 0);
+}
+
+struct Solvers;
+
+#[invoke_impl(skip("draft"))]
+impl Solvers {
+    pub fn draft(x: f64) -> f64 {
+        x
+    }
+
+    pub fn fast(x: f64) -> f64 {
+        x * 0.5
+    }
+
+    #[invoke_reference]
+    pub fn exact(x: f64) -> f64 {
+        x / 2.0
+    }
+}
+
+#[test]
+fn reference_index_matches_marked_method() {
+    assert_eq!(Solvers::REFERENCE_INDEX, 1);
+    assert_eq!(Solvers::METHOD_LIST[Solvers::REFERENCE_INDEX], "exact");
+    assert_eq!(Solvers::draft(1.0), 1.0);
+}
+
+#[test]
+fn method_names_has_the_same_type_for_every_impl_block() {
+    let names: [&[&str]; 2] = [Empty::METHOD_NAMES, Solvers::METHOD_NAMES];
+    assert!(names[0].is_empty());
+    assert_eq!(names[1], ["fast", "exact"]);
+    assert_eq!(names[1], Solvers::METHOD_LIST);
+}
+
+#[test]
+fn method_enums_follows_method_list() {
+    let pairs = Solvers::METHOD_LIST
+        .iter()
+        .zip(Solvers::METHOD_ENUMS)
+        .collect::<Vec<_>>();
+    assert_eq!(pairs.len(), Solvers::METHOD_COUNT);
+    for (name, variant) in pairs {
+        assert_eq!(*name, <&str>::from(variant));
+    }
+    assert_eq!(Solvers::METHOD_ENUMS, SolversInvokeImpl::VARIANTS);
+}
+
+const _: () = assert!(Solvers::is_method_name("exact"));
+
+#[test]
+fn is_method_name_checks_method_list() {
+    assert!(Solvers::is_method_name("fast"));
+    assert!(!Solvers::is_method_name("draft"));
+    assert!(!Solvers::is_method_name("fas"));
+    assert!(!Solvers::is_method_name("fastest"));
+    assert!(!Empty::is_method_name(""));
+}
+
+#[test]
+fn enum_mirrors_method_count_and_list() {
+    assert_eq!(SolversInvokeImpl::COUNT, Solvers::METHOD_COUNT);
+    assert_eq!(SolversInvokeImpl::NAMES, Solvers::METHOD_LIST);
+}
@@ -0,0 +1,46 @@
+use invoke_impl::invoke_impl;
+
+/// Deliberately neither Clone nor Copy
+#[derive(Debug, PartialEq)]
+struct Reading(u32);
+
+struct Sensors;
+
+#[invoke_impl(consumer_mode(all = "ref", all_enum = "owned", enumerated = "ref"))]
+impl Sensors {
+    pub fn low(base: u32) -> Reading {
+        Reading(base)
+    }
+
+    pub fn high(base: u32) -> Reading {
+        Reading(base * 10)
+    }
+}
+
+#[test]
+fn ref_consumer_mode_borrows_results() {
+    let mut seen = vec![];
+    Sensors::invoke_all(2, |r: &Reading| seen.push(r.0));
+    assert_eq!(seen, vec![2, 20]);
+
+    let mut seen = vec![];
+    Sensors::invoke_enumerated(
+        3,
+        |i, r: &Reading| seen.push((i, r.0)),
+        vec![1, 0].into_iter(),
+    );
+    assert_eq!(seen, vec![(1, 30), (0, 3)]);
+}
+
+#[test]
+fn owned_consumer_mode_moves_results() {
+    let mut kept = vec![];
+    Sensors::invoke_all_enum(4, |e, r: Reading| kept.push((e, r)));
+    assert!(matches!(kept[0], (SensorsInvokeImpl::low, Reading(4))));
+    assert!(matches!(kept[1], (SensorsInvokeImpl::high, Reading(40))));
+
+    // Invoke functions not given a consumer mode pass results by value too
+    let mut kept = vec![];
+    Sensors::invoke_subset(5, |r: Reading| kept.push(r), vec![1].into_iter());
+    assert_eq!(kept, vec![Reading(50)]);
+}
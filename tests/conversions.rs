@@ -0,0 +1,88 @@
+use invoke_impl::invoke_impl;
+
+struct Shapes;
+
+#[invoke_impl(name("shapes"); panic_conversions)]
+impl Shapes {
+    pub fn circle(r: f64) -> f64 {
+        3.0 * r * r
+    }
+
+    pub fn square(r: f64) -> f64 {
+        r * r
+    }
+}
+
+#[test]
+fn from_usize_maps_indices_to_variants() {
+    assert!(matches!(
+        ShapesInvokeImplShapes::from(0),
+        ShapesInvokeImplShapes::circle
+    ));
+    let variant: ShapesInvokeImplShapes = 1.into();
+    assert_eq!(<&str>::from(variant), "square");
+
+    // Indices passed to enumerated consumers are always in range
+    let mut names = vec![];
+    Shapes::invoke_all_enumerated_shapes(2.0, |i, _| {
+        names.push(<&str>::from(ShapesInvokeImplShapes::from(i)))
+    });
+    assert_eq!(names, vec!["circle", "square"]);
+}
+
+#[test]
+#[should_panic(expected = "Index 2 is out of range")]
+fn from_usize_panics_out_of_range() {
+    let _ = ShapesInvokeImplShapes::from(2);
+}
+
+struct Modes;
+
+#[invoke_impl(default_variant(balanced))]
+impl Modes {
+    pub fn fast(x: u32) -> u32 {
+        x
+    }
+
+    pub fn balanced(x: u32) -> u32 {
+        x * 2
+    }
+}
+
+#[derive(Default)]
+struct Config {
+    mode: ModesInvokeImpl,
+}
+
+#[test]
+fn default_is_the_configured_variant() {
+    assert!(matches!(
+        ModesInvokeImpl::default(),
+        ModesInvokeImpl::balanced
+    ));
+    assert!(matches!(Config::default().mode, ModesInvokeImpl::balanced));
+
+    let mut results = vec![];
+    Modes::invoke_enum(
+        3,
+        |_, r| results.push(r),
+        std::iter::once(Config::default().mode),
+    );
+    assert_eq!(results, vec![6]);
+}
+
+#[test]
+fn usize_round_trips() {
+    for i in 0..Modes::METHOD_COUNT {
+        let variant = ModesInvokeImpl::try_from(i).unwrap();
+        assert_eq!(usize::from(variant), i);
+    }
+    assert!(ModesInvokeImpl::try_from(Modes::METHOD_COUNT).is_err());
+
+    let mut indices = vec![];
+    Modes::invoke_all_enum(1, |e, _| indices.push(usize::from(e)));
+    assert_eq!(indices, vec![0, 1]);
+
+    // With panic_conversions, the index conversion goes through From<usize> instead
+    assert_eq!(usize::from(ShapesInvokeImplShapes::from(1)), 1);
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "dump")]
+
+use std::path::PathBuf;
+use std::sync::Once;
+
+/// Compiles and runs tests/dump/expansions.rs with INVOKE_IMPL_DUMP_DIR set, so that only its
+/// expansions are dumped, and returns the directory they are dumped to
+fn dump_dir() -> PathBuf {
+    static DUMPED: Once = Once::new();
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("invoke_impl_dump");
+    DUMPED.call_once(|| {
+        std::env::set_var("INVOKE_IMPL_DUMP_DIR", &dir);
+        trybuild::TestCases::new().pass("tests/dump/expansions.rs");
+    });
+    dir
+}
+
+fn read_dump(enum_name: &str) -> String {
+    std::fs::read_to_string(dump_dir().join(format!("{}.rs", enum_name))).unwrap()
+}
+
+#[test]
+fn expansion_is_written_to_dump_dir() {
+    let dumped = read_dump("DumpedInvokeImplDumpTest");
+    assert!(dumped.contains("pub fn invoke_all_dump_test("));
+    assert!(dumped.contains("pub enum DumpedInvokeImplDumpTest"));
+}
+
+#[test]
+fn doc_hidden_is_forwarded_to_variant() {
+    let dumped = read_dump("HiddenHelperInvokeImpl");
+    assert!(dumped.contains("    visible,\n    #[doc(hidden)]\n    hidden,\n"));
+}
+
+#[test]
+fn doc_comments_are_forwarded_to_variants() {
+    let dumped = read_dump("DocumentedInvokeImpl");
+    let declaration = &dumped[dumped.find("pub enum DocumentedInvokeImpl").unwrap()..];
+    let declaration = &declaration[..declaration.find('}').unwrap()];
+    assert!(declaration.contains("/// Takes the fast path\n    fast,\n    slow,\n"));
+    assert!(!declaration.contains("inline"));
+}
+
+#[test]
+fn invoke_functions_get_their_own_doc_comments() {
+    let dumped = read_dump("DocumentedInvokeImpl");
+    let invoke_all = &dumped[..dumped.find("pub fn invoke_all(").unwrap()];
+    let attrs = &invoke_all[invoke_all.rfind('}').unwrap()..];
+    assert!(attrs.contains("/// Invokes every function of the impl block in order.\n"));
+    assert!(!attrs.contains("Takes the fast path"));
+    assert!(!attrs.contains("inline"));
+}
+
+#[test]
+fn non_generic_calls_have_no_turbofish() {
+    let dumped = read_dump("PlainInvokeImpl");
+    assert!(dumped.contains("self.get(i)"));
+    assert!(!dumped.contains("::<>"));
+}
@@ -0,0 +1,67 @@
+use invoke_impl::invoke_impl;
+
+struct Dumped;
+
+#[invoke_impl(name("dump_test"))]
+impl Dumped {
+    pub fn fn1(i: i32) -> i32 {
+        i
+    }
+
+    pub fn fn2(i: i32) -> i32 {
+        i + 1
+    }
+}
+
+struct HiddenHelper;
+
+#[invoke_impl]
+impl HiddenHelper {
+    pub fn visible(i: i32) -> i32 {
+        i
+    }
+
+    #[doc(hidden)]
+    pub fn hidden(i: i32) -> i32 {
+        i
+    }
+}
+
+struct Documented;
+
+#[invoke_impl]
+impl Documented {
+    /// Takes the fast path
+    #[inline]
+    pub fn fast(i: i32) -> i32 {
+        i
+    }
+
+    pub fn slow(i: i32) -> i32 {
+        i
+    }
+}
+
+struct Plain(i32);
+
+#[invoke_impl]
+impl Plain {
+    pub fn get(&self, i: i32) -> i32 {
+        self.0 + i
+    }
+
+    pub fn double(&self, i: i32) -> i32 {
+        self.0 * i
+    }
+}
+
+fn main() {
+    assert_eq!(Dumped::METHOD_COUNT_dump_test, 2);
+    assert_eq!(HiddenHelper::METHOD_LIST, ["visible", "hidden"]);
+    assert!(matches!(
+        HiddenHelperInvokeImpl::try_from("hidden"),
+        Ok(HiddenHelperInvokeImpl::hidden)
+    ));
+    assert_eq!(Documented::invoke_all_collect(3), vec![3, 3]);
+    assert_eq!(Plain(2).invoke_all_collect(3), vec![5, 6]);
+}
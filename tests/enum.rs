@@ -0,0 +1,229 @@
+use invoke_impl::invoke_impl;
+use std::collections::{HashMap, HashSet};
+
+struct Codecs;
+
+#[invoke_impl]
+impl Codecs {
+    pub fn gzip(data: &[u8]) -> usize {
+        data.len() / 2
+    }
+
+    pub fn zstd(data: &[u8]) -> usize {
+        data.len() / 3
+    }
+
+    pub fn lz4(data: &[u8]) -> usize {
+        data.len() / 4
+    }
+}
+
+#[test]
+fn variants_are_distinct_hash_keys() {
+    let variants = CodecsInvokeImpl::iter().copied().collect::<HashSet<_>>();
+    assert_eq!(variants.len(), Codecs::METHOD_COUNT);
+
+    let mut sizes = HashMap::new();
+    Codecs::invoke_all_enum(&[0; 12], |codec, size| {
+        sizes.insert(codec, size);
+    });
+    assert_eq!(sizes[&CodecsInvokeImpl::zstd], 4);
+    assert_eq!(CodecsInvokeImpl::lz4, CodecsInvokeImpl::lz4);
+    assert_ne!(CodecsInvokeImpl::gzip, CodecsInvokeImpl::lz4);
+}
+
+#[test]
+fn invoke_all_enum_fold_builds_report_by_variant() {
+    let report = Codecs::invoke_all_enum_fold(&[0; 24], vec![], |mut report, codec, size| {
+        report.push((codec, size));
+        report
+    });
+    assert_eq!(
+        report,
+        vec![
+            (CodecsInvokeImpl::gzip, 12),
+            (CodecsInvokeImpl::zstd, 8),
+            (CodecsInvokeImpl::lz4, 6)
+        ]
+    );
+}
+
+const SECOND: usize = CodecsInvokeImpl::zstd.index();
+const FIRST: CodecsInvokeImpl = CodecsInvokeImpl::VARIANTS[0];
+
+#[test]
+fn variants_array_matches_iter() {
+    assert!(matches!(FIRST, CodecsInvokeImpl::gzip));
+    assert_eq!(CodecsInvokeImpl::VARIANTS.len(), Codecs::METHOD_COUNT);
+    assert!(CodecsInvokeImpl::VARIANTS
+        .iter()
+        .eq(CodecsInvokeImpl::iter()));
+}
+
+#[test]
+fn index_and_from_index_round_trip() {
+    for variant in CodecsInvokeImpl::iter() {
+        assert_eq!(
+            CodecsInvokeImpl::from_index(variant.index()),
+            Some(*variant)
+        );
+        assert_eq!(variant.index(), usize::from(*variant));
+    }
+    assert_eq!(SECOND, 1);
+    assert_eq!(CodecsInvokeImpl::from_index(Codecs::METHOD_COUNT), None);
+}
+
+#[test]
+fn parse_round_trips_variant_names() {
+    for variant in CodecsInvokeImpl::iter() {
+        let name: &str = (*variant).into();
+        assert_eq!(name.parse::<CodecsInvokeImpl>(), Ok(*variant));
+    }
+    assert!("brotli".parse::<CodecsInvokeImpl>().is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn invoke_enum_collect_keys_results_by_variant() {
+    let selection = vec![CodecsInvokeImpl::lz4, CodecsInvokeImpl::gzip];
+    let sizes = Codecs::invoke_enum_collect(&[0; 24], selection);
+    assert_eq!(
+        sizes,
+        vec![(CodecsInvokeImpl::lz4, 6), (CodecsInvokeImpl::gzip, 12)]
+    );
+    assert!(Codecs::invoke_enum_collect(&[0; 24], vec![]).is_empty());
+}
+
+struct Ciphers;
+
+#[invoke_impl]
+impl Ciphers {
+    #[invoke_serde_name("v1_rot")]
+    pub fn caesar(shift: u8) -> u8 {
+        shift % 26
+    }
+
+    pub fn vigenere(shift: u8) -> u8 {
+        shift.wrapping_mul(3)
+    }
+}
+
+#[test]
+fn serialized_names_can_differ_from_variant_names() {
+    assert_eq!(CiphersInvokeImpl::caesar.serialized_name(), "v1_rot");
+    assert_eq!(<&str>::from(CiphersInvokeImpl::caesar), "caesar");
+    assert_eq!(CiphersInvokeImpl::vigenere.serialized_name(), "vigenere");
+    assert_eq!(Ciphers::invoke_all_tuple(30), (4, 90));
+
+    assert_eq!(
+        CiphersInvokeImpl::from_serialized_name("v1_rot"),
+        Some(CiphersInvokeImpl::caesar)
+    );
+    assert_eq!(CiphersInvokeImpl::from_serialized_name("caesar"), None);
+    assert_eq!(
+        CiphersInvokeImpl::try_from("caesar"),
+        Ok(CiphersInvokeImpl::caesar)
+    );
+}
+
+struct Keywords;
+
+#[invoke_impl(rename(r#type = "Type", r#match = "Match"); default_variant(r#match))]
+impl Keywords {
+    pub fn r#type(x: u8) -> u8 {
+        x
+    }
+
+    pub fn r#match(x: u8) -> u8 {
+        x + 1
+    }
+
+    pub fn other(x: u8) -> u8 {
+        x + 2
+    }
+}
+
+#[test]
+fn renamed_variants_dispatch_to_their_functions() {
+    assert_eq!(
+        KeywordsInvokeImpl::VARIANTS,
+        [
+            KeywordsInvokeImpl::Type,
+            KeywordsInvokeImpl::Match,
+            KeywordsInvokeImpl::other
+        ]
+    );
+    assert_eq!(KeywordsInvokeImpl::default(), KeywordsInvokeImpl::Match);
+
+    let mut results = vec![];
+    Keywords::invoke_all_enum(1, |e, r| results.push((e, r)));
+    assert_eq!(
+        results,
+        vec![
+            (KeywordsInvokeImpl::Type, 1),
+            (KeywordsInvokeImpl::Match, 2),
+            (KeywordsInvokeImpl::other, 3)
+        ]
+    );
+
+    let mut results = vec![];
+    Keywords::invoke_enum(
+        1,
+        |e, r| results.push((e, r)),
+        [KeywordsInvokeImpl::Match].into_iter(),
+    );
+    assert_eq!(results, vec![(KeywordsInvokeImpl::Match, 2)]);
+
+    assert_eq!(<&str>::from(KeywordsInvokeImpl::Match), "Match");
+    assert_eq!(
+        KeywordsInvokeImpl::try_from("Type"),
+        Ok(KeywordsInvokeImpl::Type)
+    );
+}
+
+struct Opcodes;
+
+#[invoke_impl(repr(u16))]
+impl Opcodes {
+    pub fn load(x: u8) -> u8 {
+        x
+    }
+
+    pub fn store(x: u8) -> u8 {
+        x + 1
+    }
+
+    pub fn jump(x: u8) -> u8 {
+        x + 2
+    }
+}
+
+#[test]
+fn repr_discriminants_follow_impl_order() {
+    assert_eq!(std::mem::size_of::<OpcodesInvokeImpl>(), 2);
+    assert_eq!(OpcodesInvokeImpl::load.as_u16(), 0);
+    assert_eq!(OpcodesInvokeImpl::store.as_u16(), 1);
+    assert_eq!(OpcodesInvokeImpl::jump as u16, 2);
+    for variant in OpcodesInvokeImpl::iter() {
+        assert_eq!(variant.as_u16() as usize, variant.index());
+    }
+    assert_eq!(Opcodes::invoke_all_tuple(1), (1, 2, 3));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn invoke_by_name_dispatches_parsed_names() {
+    let mut seen = vec![];
+    let outcome = Opcodes::invoke_by_name(1, |e, r| seen.push((e, r)), "jump load".split(' '));
+    assert_eq!(outcome, Ok(()));
+    assert_eq!(
+        seen,
+        vec![(OpcodesInvokeImpl::jump, 3), (OpcodesInvokeImpl::load, 1)]
+    );
+
+    // An unknown name is reported before any function runs
+    let mut seen = vec![];
+    let outcome = Opcodes::invoke_by_name(1, |e, r| seen.push((e, r)), "load halt".split(' '));
+    assert_eq!(outcome, Err(String::from("halt")));
+    assert!(seen.is_empty());
+}
@@ -0,0 +1,114 @@
+use invoke_impl::invoke_impl;
+use std::cell::RefCell;
+
+#[derive(Default)]
+struct Steps {
+    called: RefCell<Vec<&'static str>>,
+}
+
+#[invoke_impl]
+impl Steps {
+    pub fn one(&self, i: i32) -> Result<i32, String> {
+        self.called.borrow_mut().push("one");
+        Ok(i)
+    }
+
+    pub fn two(&self, i: i32) -> Result<i32, String> {
+        self.called.borrow_mut().push("two");
+        Ok(i * 2)
+    }
+
+    pub fn three(&self, i: i32) -> Result<i32, String> {
+        self.called.borrow_mut().push("three");
+        Err(format!("three failed on {}", i))
+    }
+
+    pub fn four(&self, i: i32) -> Result<i32, String> {
+        self.called.borrow_mut().push("four");
+        Ok(i * 4)
+    }
+
+    pub fn five(&self, i: i32) -> Result<i32, String> {
+        self.called.borrow_mut().push("five");
+        Ok(i * 5)
+    }
+}
+
+#[test]
+fn invoke_all_try_stops_at_first_err() {
+    let steps = Steps::default();
+    let mut results = vec![];
+    let outcome = steps.invoke_all_try(3, |r| results.push(r));
+    assert_eq!(outcome, Err(String::from("three failed on 3")));
+    assert_eq!(results, vec![3, 6]);
+    assert_eq!(*steps.called.borrow(), vec!["one", "two", "three"]);
+}
+
+#[derive(Default)]
+struct Lookups {
+    called: RefCell<Vec<&'static str>>,
+}
+
+#[invoke_impl]
+impl Lookups {
+    pub fn cache(&self, key: &str) -> Option<String> {
+        self.called.borrow_mut().push("cache");
+        key.strip_prefix("cache:").map(String::from)
+    }
+
+    pub fn disk(&self, key: &str) -> Option<String> {
+        self.called.borrow_mut().push("disk");
+        key.strip_prefix("disk:").map(String::from)
+    }
+
+    pub fn network(&self, key: &str) -> Option<String> {
+        self.called.borrow_mut().push("network");
+        Some(format!("{} from network", key))
+    }
+
+    pub fn fallback(&self, key: &str) -> Option<String> {
+        self.called.borrow_mut().push("fallback");
+        Some(format!("{} from fallback", key))
+    }
+}
+
+#[test]
+fn invoke_all_first_some_stops_at_first_some() {
+    let lookups = Lookups::default();
+    assert_eq!(
+        lookups.invoke_all_first_some("net:a"),
+        Some(String::from("net:a from network"))
+    );
+    assert_eq!(*lookups.called.borrow(), vec!["cache", "disk", "network"]);
+}
+
+struct Uploads;
+
+#[invoke_impl]
+impl Uploads {
+    pub fn primary(size: u32) -> Result<u32, String> {
+        Ok(size)
+    }
+
+    pub fn mirror(size: u32) -> Result<u32, String> {
+        Err(format!("mirror rejected {}", size))
+    }
+
+    pub fn backup(size: u32) -> Result<u32, String> {
+        Err(format!("backup rejected {}", size))
+    }
+
+    pub fn archive(size: u32) -> Result<u32, String> {
+        Ok(size / 2)
+    }
+}
+
+#[test]
+fn invoke_all_count_ok_counts_without_stopping() {
+    assert_eq!(Uploads::invoke_all_count_ok(10), 2);
+
+    // The Err of the third step doesn't keep the last two from running
+    let steps = Steps::default();
+    assert_eq!(steps.invoke_all_count_ok(1), 4);
+    assert_eq!(steps.called.borrow().len(), 5);
+}
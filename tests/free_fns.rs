@@ -0,0 +1,49 @@
+#![cfg(feature = "std")]
+
+use invoke_impl::invoke_impl;
+
+pub struct Checksums;
+
+#[invoke_impl(free_fns; clone(0))]
+impl Checksums {
+    pub fn sum(data: Vec<u8>) -> u32 {
+        data.iter().map(|&b| b as u32).sum()
+    }
+
+    pub fn xor(data: Vec<u8>) -> u32 {
+        data.iter().fold(0, |acc, &b| acc ^ b as u32)
+    }
+}
+
+pub struct Units;
+
+#[invoke_impl(free_fns; name("SCALED"))]
+impl Units {
+    pub fn identity(x: f64) -> Self {
+        let _ = x;
+        Units
+    }
+}
+
+#[test]
+fn free_functions_delegate_to_invoke_functions() {
+    let mut results = vec![];
+    checksums_invoke::all(vec![1, 2, 3], |r| results.push(r));
+    assert_eq!(results, vec![6, 0]);
+
+    assert_eq!(checksums_invoke::all_collect(vec![4, 4]), vec![8, 0]);
+    assert_eq!(
+        checksums_invoke::all_collect(vec![7]),
+        Checksums::invoke_all_collect(vec![7])
+    );
+
+    let mut seen = vec![];
+    checksums_invoke::r#enum(
+        vec![1],
+        |e, r| seen.push((e, r)),
+        [ChecksumsInvokeImpl::xor].into_iter(),
+    );
+    assert_eq!(seen, vec![(ChecksumsInvokeImpl::xor, 1)]);
+
+    assert_eq!(units_invoke_scaled::all_collect(1.0).len(), 1);
+}
@@ -0,0 +1,177 @@
+use invoke_impl::invoke_impl;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+struct ConstGenerics;
+
+#[invoke_impl]
+impl ConstGenerics {
+    pub fn sum<const N: usize>(values: [i32; N]) -> i32 {
+        values.iter().sum()
+    }
+
+    pub fn len<const N: usize>(values: [i32; N]) -> i32 {
+        let _ = values;
+        N as i32
+    }
+
+    pub fn first<const N: usize>(values: [i32; N]) -> i32 {
+        values[0]
+    }
+}
+
+#[test]
+fn const_generic_invoke_all() {
+    let mut results = vec![];
+    ConstGenerics::invoke_all([1, 2, 3, 4], |r| results.push(r));
+    assert_eq!(results, vec![10, 4, 1]);
+}
+
+#[test]
+fn const_generic_invoke_enumerated() {
+    let mut results = vec![];
+    ConstGenerics::invoke_enumerated([5, 6], |i, r| results.push((i, r)), [2, 0].into_iter());
+    assert_eq!(results, vec![(2, 5), (0, 11)]);
+}
+
+struct Lifetimes;
+
+#[invoke_impl]
+impl Lifetimes {
+    pub fn left<'a, T>(x: &'a T, y: &T) -> &'a T {
+        let _ = y;
+        x
+    }
+
+    pub fn also_left<'a, T>(x: &'a T, y: &T) -> &'a T {
+        let _ = y;
+        x
+    }
+}
+
+#[test]
+fn lifetime_generic_invoke_all() {
+    let value = String::from("borrowed");
+    let mut results = vec![];
+    {
+        let other = String::from("temporary");
+        Lifetimes::invoke_all(&value, &other, |r| results.push(r));
+    }
+    assert_eq!(results, vec![&value, &value]);
+}
+
+struct WhereBounds;
+
+#[invoke_impl(clone(0); free_fns)]
+impl WhereBounds {
+    pub fn plain<T>(x: T) -> String
+    where
+        T: Clone + ToString,
+    {
+        x.to_string()
+    }
+
+    pub fn twice<T>(x: T) -> String
+    where
+        T: Clone + ToString,
+    {
+        x.clone().to_string() + &x.to_string()
+    }
+}
+
+#[test]
+fn where_clause_bounds_carry_through() {
+    let mut results = vec![];
+    WhereBounds::invoke_all(7, |r| results.push(r));
+    assert_eq!(results, vec!["7", "77"]);
+
+    let mut results = vec![];
+    WhereBounds::invoke_subset('a', |r| results.push(r), [1].into_iter());
+    assert_eq!(results, vec!["aa"]);
+
+    let mut results = vec![];
+    WhereBounds::invoke_all_enum("b", |e, r| results.push((e, r)));
+    assert_eq!(
+        results,
+        vec![
+            (WhereBoundsInvokeImpl::plain, "b".to_string()),
+            (WhereBoundsInvokeImpl::twice, "bb".to_string())
+        ]
+    );
+
+    let mut results = vec![];
+    WhereBounds::invoke_enumerated(1.5, |i, r| results.push((i, r)), [1, 0].into_iter());
+    assert_eq!(
+        results,
+        vec![(1, "1.51.5".to_string()), (0, "1.5".to_string())]
+    );
+
+    assert_eq!(
+        WhereBounds::invoke_all_fold(3, String::new(), |acc, r| acc + &r),
+        "333"
+    );
+    #[cfg(feature = "std")]
+    assert_eq!(WhereBounds::invoke_all_collect(4u8), vec!["4", "44"]);
+    #[cfg(feature = "std")]
+    assert_eq!(where_bounds_invoke::all_collect(5u8), vec!["5", "55"]);
+}
+
+struct Container<T>(PhantomData<T>);
+
+#[invoke_impl]
+impl<T: Default + Debug> Container<T> {
+    pub fn describe(label: &str) -> String {
+        format!("{}: {:?}", label, T::default())
+    }
+
+    pub fn size(label: &str) -> String {
+        format!("{}: {}", label, std::mem::size_of::<T>())
+    }
+}
+
+#[test]
+fn associated_functions_of_generic_struct() {
+    let mut results = vec![];
+    Container::<u16>::invoke_all("x", |r| results.push(r));
+    assert_eq!(results, vec!["x: 0", "x: 2"]);
+
+    #[cfg(feature = "std")]
+    assert_eq!(
+        Container::<String>::invoke_all_collect("y"),
+        vec![
+            String::from("y: \"\""),
+            format!("y: {}", std::mem::size_of::<String>())
+        ]
+    );
+    assert_eq!(Container::<u8>::run_size("z"), "z: 1");
+}
+
+struct Pair<T>(T);
+
+// The impl's own T stays in scope of the generated functions, while the functions' U is forwarded
+#[invoke_impl(clone(0))]
+impl<T: Clone> Pair<T> {
+    pub fn left<U: Clone>(&self, other: U) -> (T, U) {
+        (self.0.clone(), other)
+    }
+
+    pub fn right<U: Clone>(&self, other: U) -> (T, U) {
+        (self.0.clone(), other)
+    }
+}
+
+#[test]
+fn impl_generics_alongside_function_generics() {
+    let pair = Pair(String::from("t"));
+    #[cfg(feature = "std")]
+    assert_eq!(
+        pair.invoke_all_collect::<u8>(1),
+        vec![(String::from("t"), 1), (String::from("t"), 1)]
+    );
+    let mut seen = vec![];
+    pair.invoke_all_enum(2u32, |e, r| seen.push((e, r.1)));
+    assert!(matches!(
+        seen[..],
+        [(PairInvokeImpl::left, 2), (PairInvokeImpl::right, 2)]
+    ));
+}
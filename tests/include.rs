@@ -0,0 +1,24 @@
+use invoke_impl::invoke_impl;
+
+struct Passes;
+
+#[invoke_impl(include("forward", "backward"))]
+impl Passes {
+    pub fn forward(x: f64) -> f64 {
+        Passes::scale() * x
+    }
+
+    pub fn backward(x: f64) -> f64 {
+        x / Passes::scale()
+    }
+
+    fn scale() -> f64 {
+        2.0
+    }
+}
+
+#[test]
+fn only_included_functions_are_invoked() {
+    assert_eq!(Passes::METHOD_LIST, ["forward", "backward"]);
+    assert_eq!(Passes::invoke_all_tuple(8.0), (16.0, 4.0));
+}
@@ -0,0 +1,59 @@
+use invoke_impl::invoke_mod;
+
+#[invoke_mod]
+mod shapes {
+    /// Area of a square
+    pub fn square((x, _): (f64, f64)) -> f64 {
+        x * x
+    }
+
+    #[invoke_reference]
+    pub fn circle((x, _): (f64, f64)) -> f64 {
+        3.0 * x * x
+    }
+
+    pub fn triangle((x, y): (f64, f64)) -> f64 {
+        x * y / 2.0
+    }
+}
+
+#[test]
+fn free_invoke_functions_call_module_functions() {
+    assert_eq!(shapes::square((2.0, 1.0)), 4.0);
+    assert_eq!(shapes::METHOD_COUNT, 3);
+    assert_eq!(shapes::METHOD_LIST, ["square", "circle", "triangle"]);
+    assert_eq!(shapes::REFERENCE_INDEX, 1);
+
+    #[cfg(feature = "std")]
+    assert_eq!(shapes::invoke_all_collect((2.0, 2.0)), vec![4.0, 12.0, 2.0]);
+
+    let mut areas = vec![];
+    shapes::invoke_all_enum((1.0, 1.0), |shape, area| areas.push((shape, area)));
+    assert!(matches!(
+        areas[..],
+        [
+            (shapes::ShapesInvokeImpl::square, _),
+            (shapes::ShapesInvokeImpl::circle, _),
+            (shapes::ShapesInvokeImpl::triangle, _)
+        ]
+    ));
+}
+
+#[invoke_mod(name("scaled"); clone(0))]
+mod labels {
+    pub fn upper(s: String) -> String {
+        s.to_uppercase()
+    }
+
+    pub fn doubled(s: String) -> String {
+        s.repeat(2)
+    }
+}
+
+#[test]
+fn arguments_are_passed_to_invoke_impl() {
+    let mut labels = vec![];
+    labels::invoke_all_scaled(String::from("ab"), |l| labels.push(l));
+    assert_eq!(labels, vec!["AB", "abab"]);
+    assert_eq!(labels::METHOD_COUNT_scaled, 2);
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "std")]
+
+use invoke_impl::invoke_impl;
+
+pub struct Widget {
+    width: u32,
+    height: u32,
+}
+
+#[invoke_impl(invoker("WidgetInvoker"))]
+impl Widget {
+    pub fn area(&self, scale: u32) -> u32 {
+        self.width * self.height * scale
+    }
+
+    pub fn perimeter(&self, scale: u32) -> u32 {
+        2 * (self.width + self.height) * scale
+    }
+}
+
+pub struct Labelled<T> {
+    label: T,
+}
+
+#[invoke_impl(invoker("LabelledInvoker"))]
+impl<T: Clone> Labelled<T> {
+    pub fn once(&self) -> Vec<T> {
+        vec![self.label.clone()]
+    }
+
+    pub fn twice(&self) -> Vec<T> {
+        vec![self.label.clone(), self.label.clone()]
+    }
+}
+
+#[test]
+fn invoker_methods_call_the_invoke_functions() {
+    let widget = Widget {
+        width: 2,
+        height: 3,
+    };
+    let invoker = WidgetInvoker::new(&widget);
+
+    let mut results = vec![];
+    invoker.all(1, |r| results.push(r));
+    assert_eq!(results, vec![6, 10]);
+
+    let mut results = vec![];
+    invoker.subset(2, |r| results.push(r), vec![1].into_iter());
+    assert_eq!(results, vec![20]);
+
+    let mut results = vec![];
+    invoker.enumerated(1, |i, r| results.push((i, r)), vec![1, 0].into_iter());
+    assert_eq!(results, vec![(1, 10), (0, 6)]);
+
+    assert_eq!(invoker.all_collect(3), widget.invoke_all_collect(3));
+}
+
+#[test]
+fn invoker_takes_the_generics_of_the_impl_block() {
+    let labelled = Labelled { label: "x" };
+    let lengths = LabelledInvoker::new(&labelled)
+        .all_collect()
+        .into_iter()
+        .map(|labels| labels.len())
+        .collect::<Vec<_>>();
+    assert_eq!(lengths, vec![1, 2]);
+}
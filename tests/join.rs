@@ -0,0 +1,38 @@
+#![cfg(feature = "join")]
+
+use futures::channel::oneshot;
+use invoke_impl::invoke_impl;
+use std::cell::RefCell;
+
+/// The first function can only finish once the second has run, so awaiting them one after the
+/// other would never complete
+struct Handoff {
+    sender: RefCell<Option<oneshot::Sender<u32>>>,
+    receiver: RefCell<Option<oneshot::Receiver<u32>>>,
+}
+
+#[invoke_impl]
+impl Handoff {
+    pub async fn waiting(&self, i: u32) -> u32 {
+        let receiver = self.receiver.borrow_mut().take().unwrap();
+        receiver.await.unwrap() + i
+    }
+
+    pub async fn sending(&self, i: u32) -> u32 {
+        let sender = self.sender.borrow_mut().take().unwrap();
+        sender.send(100).unwrap();
+        i * 2
+    }
+}
+
+#[tokio::test]
+async fn invoke_all_join_awaits_concurrently() {
+    let (sender, receiver) = oneshot::channel();
+    let handoff = Handoff {
+        sender: RefCell::new(Some(sender)),
+        receiver: RefCell::new(Some(receiver)),
+    };
+    let mut results = vec![];
+    handoff.invoke_all_join(5, |r| results.push(r)).await;
+    assert_eq!(results, vec![105, 10]);
+}
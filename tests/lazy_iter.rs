@@ -0,0 +1,49 @@
+#![cfg(feature = "std")]
+
+use invoke_impl::invoke_impl;
+use std::cell::RefCell;
+
+#[derive(Default)]
+struct Stages {
+    called: RefCell<Vec<&'static str>>,
+}
+
+#[invoke_impl(clone(0))]
+impl Stages {
+    pub fn parse(&self, input: String) -> usize {
+        self.called.borrow_mut().push("parse");
+        input.len()
+    }
+
+    pub fn check(&self, input: String) -> usize {
+        self.called.borrow_mut().push("check");
+        input.len() * 2
+    }
+
+    pub fn emit(&self, input: String) -> usize {
+        self.called.borrow_mut().push("emit");
+        input.len() * 3
+    }
+}
+
+#[test]
+fn invoke_all_iter_only_calls_what_is_iterated() {
+    let stages = Stages::default();
+    let mut results = stages.invoke_all_iter(String::from("abcd"));
+    assert!(stages.called.borrow().is_empty());
+    assert_eq!(results.nth(1), Some(8));
+    assert_eq!(*stages.called.borrow(), vec!["check"]);
+    assert_eq!(results.collect::<Vec<_>>(), vec![12]);
+    assert_eq!(*stages.called.borrow(), vec!["check", "emit"]);
+}
+
+#[test]
+fn invoke_all_iter_take_stops_calling() {
+    let stages = Stages::default();
+    let results = stages
+        .invoke_all_iter(String::from("ab"))
+        .take(2)
+        .collect::<Vec<_>>();
+    assert_eq!(results, vec![2, 4]);
+    assert_eq!(*stages.called.borrow(), vec!["parse", "check"]);
+}
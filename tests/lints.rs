@@ -0,0 +1,45 @@
+#![deny(warnings)]
+#![deny(clippy::too_many_arguments)]
+
+use invoke_impl::invoke_impl;
+
+struct Quiet;
+
+#[invoke_impl]
+impl Quiet {
+    pub fn first(x: u8) -> u8 {
+        x
+    }
+
+    pub fn second(x: u8) -> u8 {
+        x + 1
+    }
+}
+
+#[test]
+fn generated_code_compiles_without_warnings() {
+    assert_eq!(QuietInvokeImpl::iter().count(), Quiet::METHOD_COUNT);
+    #[cfg(feature = "std")]
+    assert_eq!(Quiet::invoke_all_collect(1), vec![1, 2]);
+}
+
+struct Wide;
+
+// Six parameters are fine, but the invoke functions add a consumer and an iterator to them
+#[invoke_impl]
+impl Wide {
+    pub fn sum(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8) -> u8 {
+        a + b + c + d + e + f
+    }
+
+    pub fn max(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8) -> u8 {
+        [a, b, c, d, e, f].into_iter().max().unwrap()
+    }
+}
+
+#[test]
+fn invoke_functions_with_many_parameters_pass_clippy() {
+    let mut results = vec![];
+    Wide::invoke_subset(1, 2, 3, 4, 5, 6, |r| results.push(r), [1, 0].into_iter());
+    assert_eq!(results, vec![6, 21]);
+}
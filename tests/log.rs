@@ -0,0 +1,47 @@
+#![cfg(feature = "log")]
+
+use invoke_impl::invoke_impl;
+use std::sync::Mutex;
+
+static MESSAGES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+struct Recorder;
+
+impl log::Log for Recorder {
+    fn enabled(&self, _: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        MESSAGES.lock().unwrap().push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+static RECORDER: Recorder = Recorder;
+
+struct Steps;
+
+#[invoke_impl]
+impl Steps {
+    pub fn parse(x: u8) -> u8 {
+        x
+    }
+
+    pub fn check(x: u8) -> u8 {
+        x + 1
+    }
+}
+
+#[test]
+fn every_invoked_function_is_logged() {
+    log::set_logger(&RECORDER).unwrap();
+    log::set_max_level(log::LevelFilter::Debug);
+
+    Steps::invoke_subset(0, |_| {}, vec![1, 0, 1].into_iter());
+    assert_eq!(
+        *MESSAGES.lock().unwrap(),
+        vec!["invoking check", "invoking parse", "invoking check"]
+    );
+}
@@ -0,0 +1,179 @@
+use invoke_impl::invoke_impl;
+
+struct Prefixed;
+
+#[invoke_impl(prefix("audio"))]
+impl Prefixed {
+    pub fn left(x: i32) -> i32 {
+        x
+    }
+
+    pub fn right(x: i32) -> i32 {
+        -x
+    }
+}
+
+struct Both;
+
+#[invoke_impl(prefix("video"); name("hd"))]
+impl Both {
+    pub fn top(x: i32) -> i32 {
+        x + 1
+    }
+
+    pub fn bottom(x: i32) -> i32 {
+        x - 1
+    }
+}
+
+#[test]
+fn prefix_is_prepended() {
+    let mut results = vec![];
+    Prefixed::audio_invoke_all(3, |r| results.push(r));
+    assert_eq!(results, vec![3, -3]);
+    assert_eq!(Prefixed::audio_invoke_all_tuple(2), (2, -2));
+    assert_eq!(Prefixed::METHOD_COUNT, 2);
+}
+
+#[test]
+fn prefix_and_name_combine() {
+    let mut results = vec![];
+    Both::video_invoke_all_enumerated_hd(3, |i, r| results.push((i, r)));
+    assert_eq!(results, vec![(0, 4), (1, 2)]);
+    assert_eq!(Both::METHOD_COUNT_hd, 2);
+    assert!(matches!(
+        BothInvokeImplHd::iter().next(),
+        Some(BothInvokeImplHd::top)
+    ));
+}
+
+struct Dispatched;
+
+#[invoke_impl(verb("dispatch"))]
+impl Dispatched {
+    pub fn up(x: i32) -> i32 {
+        x + 1
+    }
+
+    pub fn down(x: i32) -> i32 {
+        x - 1
+    }
+}
+
+struct Composed;
+
+#[invoke_impl(prefix("audio"); verb("dispatch"); name("hd"))]
+impl Composed {
+    pub fn loud(x: i32) -> i32 {
+        x * 2
+    }
+
+    pub fn quiet(x: i32) -> i32 {
+        x / 2
+    }
+}
+
+#[test]
+fn verb_replaces_invoke() {
+    let mut results = vec![];
+    Dispatched::dispatch_all(3, |r| results.push(r));
+    assert_eq!(results, vec![4, 2]);
+    #[cfg(feature = "std")]
+    assert_eq!(Dispatched::dispatch_all_collect(1), vec![2, 0]);
+
+    let mut results = vec![];
+    Dispatched::dispatch_subset(5, |r| results.push(r), [1].into_iter());
+    assert_eq!(results, vec![4]);
+
+    assert_eq!(Composed::audio_dispatch_all_tuple_hd(4), (8, 2));
+}
+
+#[cfg(feature = "std")]
+struct Filters;
+
+#[cfg(feature = "std")]
+#[invoke_impl]
+impl Filters {
+    pub fn blur(x: u8) -> u8 {
+        x
+    }
+
+    pub fn sharpen(x: u8) -> u8 {
+        x
+    }
+
+    pub fn invert(x: u8) -> u8 {
+        !x
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn selected_names_follow_mask_bits() {
+    assert_eq!(Filters::selected_names(0b101), vec!["blur", "invert"]);
+    assert_eq!(Filters::selected_names(0), Vec::<&str>::new());
+    // Bits past METHOD_COUNT select nothing
+    assert_eq!(Filters::selected_names(0b1010), vec!["sharpen"]);
+    assert_eq!(Both::selected_names_hd(u64::MAX), vec!["top", "bottom"]);
+}
+
+struct Codec;
+
+#[invoke_impl(enum_name("CodecKind"))]
+impl Codec {
+    pub fn raw(x: u8) -> u8 {
+        x
+    }
+
+    pub fn packed(x: u8) -> u8 {
+        x / 2
+    }
+}
+
+#[test]
+fn enum_name_replaces_the_default() {
+    let mut kinds = vec![];
+    Codec::invoke_all_enum(4, |kind: CodecKind, r| kinds.push((kind, r)));
+    assert_eq!(kinds, vec![(CodecKind::raw, 4), (CodecKind::packed, 2)]);
+
+    let mut results = vec![];
+    Codec::invoke_enum(
+        8,
+        |_, r| results.push(r),
+        vec![CodecKind::packed].into_iter(),
+    );
+    assert_eq!(results, vec![4]);
+}
+
+#[test]
+fn default_enum_name_is_pascal_case() {
+    #[cfg(feature = "std")]
+    assert_eq!(<&str>::from(FiltersInvokeImpl::blur), "blur");
+    assert_eq!(<&str>::from(BothInvokeImplHd::bottom), "bottom");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn iterator_struct_names_are_pascal_case() {
+    let _: fn(FiltersInvokeImplIter<'static, u8, 3>) -> Option<u8> = |mut iter| iter.next();
+    let _: fn(BothInvokeImplIterHd<'static, i32, 2>) -> Option<i32> = |mut iter| iter.next();
+}
+
+struct Reducers;
+
+#[invoke_impl]
+impl Reducers {
+    pub fn sum(values: &[u32]) -> u32 {
+        values.iter().sum()
+    }
+
+    // Only reserved when the parallel argument is given
+    pub fn invoke_all_par(values: &[u32]) -> u32 {
+        values.len() as u32
+    }
+}
+
+#[test]
+fn parallel_names_are_free_without_parallel() {
+    assert_eq!(Reducers::invoke_all_tuple(&[1, 2, 3]), (6, 3));
+}
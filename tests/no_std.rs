@@ -0,0 +1,39 @@
+#![cfg(not(feature = "std"))]
+#![no_std]
+
+use invoke_impl::invoke_impl;
+
+struct Filters;
+
+#[invoke_impl]
+impl Filters {
+    pub fn identity(x: i32) -> i32 {
+        x
+    }
+
+    pub fn double(x: i32) -> i32 {
+        x * 2
+    }
+
+    pub fn negate(x: i32) -> i32 {
+        -x
+    }
+}
+
+#[test]
+fn core_invoke_functions_work_without_std() {
+    let mut sum = 0;
+    Filters::invoke_all(3, |r| sum += r);
+    assert_eq!(sum, 6);
+
+    let mut out = [0; Filters::METHOD_COUNT];
+    Filters::invoke_all_into(4, &mut out);
+    assert_eq!(out, [4, 8, -4]);
+
+    assert_eq!(Filters::invoke_all_tuple(1), (1, 2, -1));
+    assert_eq!(
+        FiltersInvokeImpl::try_from("double"),
+        Ok(FiltersInvokeImpl::double)
+    );
+    assert_eq!(FiltersInvokeImpl::iter().count(), 3);
+}
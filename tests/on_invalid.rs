@@ -0,0 +1,92 @@
+use invoke_impl::invoke_impl;
+
+struct Skipping;
+
+#[invoke_impl(on_invalid(skip))]
+impl Skipping {
+    pub fn one() -> u8 {
+        1
+    }
+
+    pub fn two() -> u8 {
+        2
+    }
+}
+
+struct Panicking;
+
+#[invoke_impl(on_invalid(panic))]
+impl Panicking {
+    pub fn one() -> u8 {
+        1
+    }
+
+    pub fn two() -> u8 {
+        2
+    }
+}
+
+struct Failing;
+
+#[invoke_impl(on_invalid(error))]
+impl Failing {
+    pub fn one() -> u8 {
+        1
+    }
+
+    pub fn two() -> u8 {
+        2
+    }
+}
+
+#[test]
+fn skip_ignores_invalid_indices() {
+    let mut results = vec![];
+    Skipping::invoke_subset(|r| results.push(r), [1, 7, 0].into_iter());
+    assert_eq!(results, vec![2, 1]);
+
+    let mut results = vec![];
+    Skipping::invoke_enumerated(|i, r| results.push((i, r)), [9, 1].into_iter());
+    assert_eq!(results, vec![(1, 2)]);
+}
+
+#[test]
+#[should_panic(expected = "Iter contains invalid function index!")]
+fn panic_panics_on_invalid_indices() {
+    Panicking::invoke_subset(|_| (), [0, 2].into_iter());
+}
+
+#[test]
+fn error_returns_first_invalid_index() {
+    let mut results = vec![];
+    assert_eq!(
+        Failing::invoke_subset(|r| results.push(r), [1, 5, 0].into_iter()),
+        Err(5)
+    );
+    assert_eq!(results, vec![2]);
+
+    assert_eq!(
+        Failing::invoke_subset_lazy(|_| (), |_| (), [0, 1].into_iter()),
+        Ok(())
+    );
+    assert_eq!(
+        Failing::invoke_enumerated(|_, _| (), [3].into_iter()),
+        Err(3)
+    );
+}
+
+#[test]
+fn invoke_subset_checked_returns_first_invalid_index() {
+    // Unlike invoke_subset, it returns the invalid index whatever on_invalid says
+    let mut results = vec![];
+    assert_eq!(
+        Panicking::invoke_subset_checked(|r| results.push(r), [1, 0, 4, 1].into_iter()),
+        Err(4)
+    );
+    assert_eq!(results, vec![2, 1]);
+
+    assert_eq!(
+        Skipping::invoke_subset_checked(|_| (), [0, 1].into_iter()),
+        Ok(())
+    );
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "rayon")]
+
+use invoke_impl::invoke_impl;
+
+struct Crunchers {
+    offset: u64,
+}
+
+#[invoke_impl(clone(0, 1); parallel)]
+impl Crunchers {
+    pub fn sum(&self, n: u64, label: String) -> (String, u64) {
+        (label, self.offset + (1..=n).sum::<u64>())
+    }
+
+    pub fn product(&self, n: u64, label: String) -> (String, u64) {
+        (label, self.offset + (1..=n).product::<u64>())
+    }
+
+    pub fn squares(&self, n: u64, label: String) -> (String, u64) {
+        (label, self.offset + (1..=n).map(|i| i * i).sum::<u64>())
+    }
+}
+
+#[test]
+fn invoke_all_par_preserves_order() {
+    let crunchers = Crunchers { offset: 1 };
+    let results = crunchers.invoke_all_par(5, String::from("n"));
+    assert_eq!(
+        results,
+        vec![
+            (String::from("n"), 16),
+            (String::from("n"), 121),
+            (String::from("n"), 56),
+        ]
+    );
+}
+
+struct Gated;
+
+#[invoke_impl(clone(0); parallel)]
+impl Gated {
+    pub fn kept(n: u64) -> u64 {
+        n
+    }
+
+    #[cfg(any())]
+    pub fn dropped(n: u64) -> u64 {
+        n * 2
+    }
+
+    pub fn doubled(n: u64) -> u64 {
+        n * 3
+    }
+}
+
+#[test]
+fn invoke_all_par_skips_compiled_out_functions() {
+    assert_eq!(Gated::invoke_all_par(2), vec![2, 6]);
+}
+
+#[test]
+fn invoke_all_par_each_consumes_every_result() {
+    let crunchers = Crunchers { offset: 1 };
+    let mut results = Vec::new();
+    crunchers.invoke_all_par_each(5, String::from("n"), |r| results.push(r));
+    results.sort();
+    assert_eq!(
+        results,
+        vec![
+            (String::from("n"), 16),
+            (String::from("n"), 56),
+            (String::from("n"), 121),
+        ]
+    );
+}
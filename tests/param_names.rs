@@ -0,0 +1,191 @@
+use invoke_impl::invoke_impl;
+
+// Each function takes a parameter with the same name as one the invoke functions add before the
+// arguments, which must not collide with the prefixed names the macro generates
+#[cfg(feature = "std")]
+struct Classify;
+
+#[cfg(feature = "std")]
+#[invoke_impl]
+impl Classify {
+    pub fn double(classify: i32) -> i32 {
+        classify * 2
+    }
+
+    pub fn negate(classify: i32) -> i32 {
+        -classify
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn partition_allows_param_named_classify() {
+    let partition = Classify::invoke_all_partition(3, |r| *r > 0);
+    assert_eq!(partition[&true], vec![(0, 6)]);
+    assert_eq!(partition[&false], vec![(1, -3)]);
+}
+
+struct Target;
+
+#[invoke_impl]
+impl Target {
+    pub fn double(target: i32) -> i32 {
+        target * 2
+    }
+
+    pub fn negate(target: i32) -> i32 {
+        -target
+    }
+}
+
+#[test]
+fn extend_allows_param_named_target() {
+    let mut target = vec![0];
+    Target::invoke_all_extend(3, &mut target);
+    assert_eq!(target, vec![0, 6, -3]);
+}
+
+struct Fold;
+
+#[invoke_impl]
+impl Fold {
+    pub fn double(init: i32, fold: i32) -> i32 {
+        init * 2 + fold
+    }
+
+    pub fn negate(init: i32, fold: i32) -> i32 {
+        -init - fold
+    }
+}
+
+#[test]
+fn fold_allows_params_named_init_and_fold() {
+    assert_eq!(
+        Fold::invoke_all_fold(3, 1, 10, |acc, r| acc + r),
+        10 + 7 - 4
+    );
+}
+
+#[test]
+fn enum_fold_allows_params_named_init_and_fold() {
+    let report = Fold::invoke_all_enum_fold(3, 1, vec![], |mut report, function, r| {
+        report.push((function, r));
+        report
+    });
+    assert_eq!(
+        report,
+        vec![(FoldInvokeImpl::double, 7), (FoldInvokeImpl::negate, -4)]
+    );
+}
+
+struct Acc;
+
+#[invoke_impl]
+impl Acc {
+    pub fn double(acc: i32) -> i32 {
+        acc * 2
+    }
+
+    pub fn negate(acc: i32) -> i32 {
+        -acc
+    }
+}
+
+#[test]
+fn with_allows_param_named_acc() {
+    let mut acc = vec![];
+    Acc::invoke_all_with(3, &mut acc, |acc, r| acc.push(r));
+    assert_eq!(acc, vec![6, -3]);
+}
+
+struct Out;
+
+#[invoke_impl]
+impl Out {
+    pub fn double(out: i32) -> i32 {
+        out * 2
+    }
+
+    pub fn negate(out: i32) -> i32 {
+        -out
+    }
+}
+
+#[test]
+fn into_allows_param_named_out() {
+    let mut out = [0; Out::METHOD_COUNT];
+    Out::invoke_all_into(3, &mut out);
+    assert_eq!(out, [6, -3]);
+}
+
+#[cfg(feature = "std")]
+struct Indices;
+
+#[cfg(feature = "std")]
+#[invoke_impl]
+impl Indices {
+    pub fn double(indices: i32) -> i32 {
+        indices * 2
+    }
+
+    pub fn negate(indices: i32) -> i32 {
+        -indices
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn collect_indices_allows_param_named_indices() {
+    let indices = [1];
+    assert_eq!(Indices::invoke_all_collect_indices(3, &indices), vec![-3]);
+}
+
+struct Finish;
+
+#[invoke_impl]
+impl Finish {
+    pub fn double(finish: i32) -> i32 {
+        finish * 2
+    }
+
+    pub fn negate(finish: i32) -> i32 {
+        -finish
+    }
+}
+
+#[test]
+fn then_allows_param_named_finish() {
+    let events = std::cell::RefCell::new(vec![]);
+    Finish::invoke_all_then(
+        3,
+        |r| events.borrow_mut().push(r),
+        || events.borrow_mut().push(0),
+    );
+    assert_eq!(events.into_inner(), vec![6, -3, 0]);
+}
+
+struct Progress;
+
+#[invoke_impl]
+impl Progress {
+    pub fn double(progress: i32) -> i32 {
+        progress * 2
+    }
+
+    pub fn negate(progress: i32) -> i32 {
+        -progress
+    }
+}
+
+#[test]
+fn progress_allows_param_named_progress() {
+    let mut results = vec![];
+    let mut reports = vec![];
+    Progress::invoke_all_progress(
+        3,
+        |r| results.push(r),
+        |completed, total| reports.push((completed, total)),
+    );
+    assert_eq!(results, vec![6, -3]);
+    assert_eq!(reports, vec![(1, 2), (2, 2)]);
+}
@@ -0,0 +1,60 @@
+use invoke_impl::invoke_impl;
+
+struct Matrix;
+
+#[invoke_impl]
+impl Matrix {
+    #[cfg(feature = "join")]
+    pub fn joined(x: u8) -> u8 {
+        x
+    }
+
+    #[cfg(feature = "timing")]
+    pub fn timed(x: u8) -> u8 {
+        x + 1
+    }
+
+    #[cfg(all(feature = "join", feature = "timing"))]
+    pub fn both(x: u8) -> u8 {
+        x + 2
+    }
+
+    #[cfg(not(any(feature = "join", feature = "timing")))]
+    pub fn neither(x: u8) -> u8 {
+        x + 3
+    }
+
+    pub fn always(x: u8) -> u8 {
+        x + 4
+    }
+}
+
+fn main() {
+    let expected = [
+        cfg!(feature = "join"),
+        cfg!(feature = "timing"),
+        cfg!(all(feature = "join", feature = "timing")),
+        cfg!(not(any(feature = "join", feature = "timing"))),
+        true,
+    ];
+    let indices = (0..expected.len())
+        .filter(|&i| expected[i])
+        .collect::<Vec<_>>();
+
+    let mut enumerated = vec![];
+    Matrix::invoke_all_enumerated(0, |i, r| enumerated.push((i, r as usize)));
+    assert_eq!(enumerated, indices.iter().map(|&i| (i, i)).collect::<Vec<_>>());
+
+    for variant in MatrixInvokeImpl::iter() {
+        let index = usize::from(*variant);
+        assert!(expected[index]);
+        assert_eq!(MatrixInvokeImpl::try_from(index), Ok(*variant));
+        assert_eq!(MatrixInvokeImpl::try_from(<&str>::from(*variant)), Ok(*variant));
+    }
+
+    let mut results = vec![];
+    Matrix::invoke_enum(0, |_, r| results.push(r), MatrixInvokeImpl::iter().copied());
+    Matrix::invoke_subset(0, |r| results.push(r), indices.iter().copied());
+    Matrix::invoke_all_batch(0, |batch| results.extend_from_slice(batch));
+    assert_eq!(results.len(), 3 * indices.len());
+}
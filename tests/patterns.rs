@@ -0,0 +1,49 @@
+#![cfg(feature = "std")]
+
+use invoke_impl::invoke_impl;
+
+struct Points;
+
+#[invoke_impl]
+impl Points {
+    pub fn sum((x, y): (i32, i32), _: bool) -> i32 {
+        x + y
+    }
+
+    pub fn diff((x, y): (i32, i32), _: bool) -> i32 {
+        x - y
+    }
+}
+
+struct Scaled;
+
+#[invoke_impl]
+impl Scaled {
+    pub fn double(&scale: &i32) -> i32 {
+        scale * 2
+    }
+
+    pub fn triple(&scale: &i32) -> i32 {
+        scale * 3
+    }
+}
+
+#[test]
+fn destructured_parameters_are_forwarded() {
+    let mut results = vec![];
+    Points::invoke_all((5, 2), true, |r| results.push(r));
+    assert_eq!(results, vec![7, 3]);
+
+    let lazy = Points::invoke_all_iter((4, 1), false).collect::<Vec<_>>();
+    assert_eq!(lazy, vec![5, 3]);
+
+    let mut results = vec![];
+    Points::invoke_subset_lazy(
+        |i| ((i as i32, 1), true),
+        |r| results.push(r),
+        [1, 0].into_iter(),
+    );
+    assert_eq!(results, vec![0, 1]);
+
+    assert_eq!(Scaled::invoke_all_collect(&4), vec![8, 12]);
+}
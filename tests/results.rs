@@ -0,0 +1,301 @@
+use invoke_impl::invoke_impl;
+
+struct Arithmetic;
+
+#[invoke_impl]
+impl Arithmetic {
+    pub fn double(i: i32) -> i32 {
+        i * 2
+    }
+
+    pub fn square(i: i32) -> i32 {
+        i * i
+    }
+
+    pub fn negate(i: i32) -> i32 {
+        -i
+    }
+}
+
+#[test]
+fn invoke_all_tuple_destructures() {
+    let (double, square, negate) = Arithmetic::invoke_all_tuple(3);
+    assert_eq!(double, 6);
+    assert_eq!(square, 9);
+    assert_eq!(negate, -3);
+}
+
+struct Powers;
+
+// Thirteen functions are one more than the largest tuple, so invoke_all_tuple is left free for an
+// inherent function of that name, which would otherwise collide with it
+#[invoke_impl(skip("invoke_all_tuple"))]
+impl Powers {
+    pub fn p0(i: u64) -> u64 {
+        i.pow(0)
+    }
+    pub fn p1(i: u64) -> u64 {
+        i
+    }
+    pub fn p2(i: u64) -> u64 {
+        i.pow(2)
+    }
+    pub fn p3(i: u64) -> u64 {
+        i.pow(3)
+    }
+    pub fn p4(i: u64) -> u64 {
+        i.pow(4)
+    }
+    pub fn p5(i: u64) -> u64 {
+        i.pow(5)
+    }
+    pub fn p6(i: u64) -> u64 {
+        i.pow(6)
+    }
+    pub fn p7(i: u64) -> u64 {
+        i.pow(7)
+    }
+    pub fn p8(i: u64) -> u64 {
+        i.pow(8)
+    }
+    pub fn p9(i: u64) -> u64 {
+        i.pow(9)
+    }
+    pub fn p10(i: u64) -> u64 {
+        i.pow(10)
+    }
+    pub fn p11(i: u64) -> u64 {
+        i.pow(11)
+    }
+    pub fn p12(i: u64) -> u64 {
+        i.pow(12)
+    }
+
+    pub fn invoke_all_tuple(i: u64) -> [u64; 13] {
+        let mut out = [0; 13];
+        Powers::invoke_all_into(i, &mut out);
+        out
+    }
+}
+
+#[test]
+fn invoke_all_tuple_is_not_generated_past_twelve_functions() {
+    assert_eq!(Powers::METHOD_COUNT, 13);
+    let [one, .., last] = Powers::invoke_all_tuple(2);
+    assert_eq!((one, last), (1, 4096));
+}
+
+#[test]
+fn invoke_all_batch_passes_all_results_at_once() {
+    let mut calls = 0;
+    let mut total = 0;
+    Arithmetic::invoke_all_batch(3, |results| {
+        calls += 1;
+        total = results.iter().sum();
+    });
+    assert_eq!(calls, 1);
+    assert_eq!(total, 6 + 9 - 3);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn invoke_all_results_indexes_by_enum_and_position() {
+    let results = Arithmetic::invoke_all_results(4);
+    assert_eq!(results[ArithmeticInvokeImpl::square], 16);
+    assert_eq!(results[0], 8);
+    for variant in ArithmeticInvokeImpl::iter() {
+        assert_eq!(results[*variant], results[usize::from(*variant)]);
+    }
+    assert_eq!(results.into_vec(), Arithmetic::invoke_all_collect(4));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn invoke_all_partition_groups_by_key() {
+    let partition = Arithmetic::invoke_all_partition(3, |r| *r > 0);
+    assert_eq!(partition[&true], vec![(0, 6), (1, 9)]);
+    assert_eq!(partition[&false], vec![(2, -3)]);
+
+    let by_parity = Arithmetic::invoke_all_partition(3, |r| r.rem_euclid(2));
+    assert_eq!(by_parity[&0], vec![(0, 6)]);
+    assert_eq!(by_parity[&1], vec![(1, 9), (2, -3)]);
+}
+
+#[test]
+fn invoke_all_extend_appends_to_target() {
+    let mut results = vec![0];
+    Arithmetic::invoke_all_extend(3, &mut results);
+    Arithmetic::invoke_all_extend(2, &mut results);
+    assert_eq!(results, vec![0, 6, 9, -3, 4, 4, -2]);
+
+    let mut distinct = std::collections::BTreeSet::new();
+    Arithmetic::invoke_all_extend(2, &mut distinct);
+    assert_eq!(distinct.into_iter().collect::<Vec<_>>(), vec![-2, 4]);
+}
+
+#[test]
+fn invoke_all_fold_accumulates_results() {
+    assert_eq!(
+        Arithmetic::invoke_all_fold(3, 0, |acc, r| acc + r),
+        6 + 9 - 3
+    );
+
+    let described = Arithmetic::invoke_all_fold(2, String::new(), |mut acc, r| {
+        acc.push_str(&format!("[{}]", r));
+        acc
+    });
+    assert_eq!(described, "[4][4][-2]");
+}
+
+#[test]
+fn invoke_all_with_passes_the_accumulator_to_every_call() {
+    let mut seen = vec![1];
+    Arithmetic::invoke_all_with(3, &mut seen, |seen, r| seen.push(r));
+    Arithmetic::invoke_all_with(1, &mut seen, |seen, r| seen.push(r * 10));
+    assert_eq!(seen, vec![1, 6, 9, -3, 20, 10, -10]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn invoke_all_enumerated_collect_pairs_results_with_indices() {
+    let pairs = Arithmetic::invoke_all_enumerated_collect(4);
+    assert_eq!(pairs, vec![(0, 8), (1, 16), (2, -4)]);
+    assert!(pairs
+        .iter()
+        .map(|&(index, _)| index)
+        .eq(0..Arithmetic::METHOD_COUNT));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn invoke_all_deque_pops_in_order() {
+    let mut queue = Arithmetic::invoke_all_deque(3);
+    assert_eq!(queue.pop_front(), Some(6));
+    queue.extend(Arithmetic::invoke_all_deque(2));
+    assert_eq!(queue.pop_front(), Some(9));
+    assert_eq!(queue.pop_front(), Some(-3));
+    assert_eq!(queue, [4, 4, -2]);
+}
+
+#[cfg(feature = "std")]
+struct Ledger {
+    entries: std::cell::RefCell<Vec<&'static str>>,
+}
+
+#[cfg(feature = "std")]
+#[invoke_impl]
+impl Ledger {
+    pub fn open(&self, amount: i32) -> i32 {
+        self.entries.borrow_mut().push("open");
+        amount
+    }
+
+    pub fn charge(&self, amount: i32) -> i32 {
+        self.entries.borrow_mut().push("charge");
+        amount - 5
+    }
+
+    pub fn close(&self, amount: i32) -> i32 {
+        self.entries.borrow_mut().push("close");
+        -amount
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn invoke_all_collect_indices_runs_everything_but_keeps_some() {
+    let ledger = Ledger {
+        entries: Default::default(),
+    };
+    let full = ledger.invoke_all_collect(10);
+    let picked = ledger.invoke_all_collect_indices(10, &[2, 0, 7]);
+    assert_eq!(picked, vec![full[0], full[2]]);
+    assert!(ledger.invoke_all_collect_indices(10, &[]).is_empty());
+    assert_eq!(
+        *ledger.entries.borrow(),
+        ["open", "charge", "close"].repeat(3)
+    );
+}
+
+struct Teardown;
+
+#[invoke_impl]
+impl Teardown {
+    pub fn close_files(step: &str) -> String {
+        format!("{} files", step)
+    }
+
+    pub fn close_sockets(step: &str) -> String {
+        format!("{} sockets", step)
+    }
+
+    pub fn close_db(step: &str) -> String {
+        format!("{} db", step)
+    }
+}
+
+#[test]
+fn invoke_all_rev_runs_in_reverse_impl_order() {
+    let mut seen = vec![];
+    Teardown::invoke_all_rev("closed", |r| seen.push(r));
+    assert_eq!(seen, vec!["closed db", "closed sockets", "closed files"]);
+
+    let mut forward = vec![];
+    Teardown::invoke_all("closed", |r| forward.push(r));
+    forward.reverse();
+    assert_eq!(forward, seen);
+}
+
+#[test]
+fn invoke_all_then_finishes_after_last_consumer_call() {
+    let events = std::cell::RefCell::new(vec![]);
+    Teardown::invoke_all_then(
+        "closed",
+        |r| events.borrow_mut().push(r),
+        || events.borrow_mut().push("flushed".to_string()),
+    );
+    assert_eq!(
+        events.into_inner(),
+        vec!["closed files", "closed sockets", "closed db", "flushed"]
+    );
+}
+
+#[test]
+fn invoke_all_progress_reports_completed_and_total() {
+    let mut results = vec![];
+    let mut reports = vec![];
+    Teardown::invoke_all_progress(
+        "closed",
+        |r| results.push(r),
+        |completed, total| reports.push((completed, total)),
+    );
+    assert_eq!(results.len(), 3);
+    assert_eq!(reports, vec![(1, 3), (2, 3), (3, 3)]);
+    assert_eq!(
+        reports.last(),
+        Some(&(Teardown::METHOD_COUNT, Teardown::METHOD_COUNT))
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn invoke_all_named_map_keys_results_by_function_name() {
+    let map = Teardown::invoke_all_named_map("closed");
+    assert_eq!(map.len(), Teardown::METHOD_COUNT);
+    for name in Teardown::METHOD_LIST {
+        assert!(map.contains_key(name));
+    }
+    assert_eq!(map["close_sockets"], "closed sockets");
+    assert_eq!(map["close_db"], "closed db");
+}
+
+#[test]
+fn invoke_all_into_fills_caller_owned_slots() {
+    let mut out: [String; Teardown::METHOD_COUNT] = Default::default();
+    Teardown::invoke_all_into("closed", &mut out);
+    assert_eq!(out, ["closed files", "closed sockets", "closed db"]);
+
+    // The same storage can be reused
+    Teardown::invoke_all_into("reopened", &mut out);
+    assert_eq!(out[2], "reopened db");
+}
@@ -0,0 +1,26 @@
+use invoke_impl::invoke_impl;
+
+struct Greeter {
+    greeting: &'static str,
+}
+
+#[invoke_impl(clone(0))]
+impl Greeter {
+    pub fn formal(&self, name: String) -> String {
+        format!("{}, {}.", self.greeting, name)
+    }
+
+    pub fn casual(&self, name: String) -> String {
+        format!("{} {}!", self.greeting.to_lowercase(), name)
+    }
+}
+
+#[test]
+fn run_calls_only_the_named_method() {
+    let greeter = Greeter { greeting: "Hello" };
+    assert_eq!(greeter.run_casual(String::from("Ada")), "hello Ada!");
+    assert_eq!(
+        greeter.run_formal(String::from("Ada")),
+        greeter.formal(String::from("Ada"))
+    );
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "serde")]
+
+use invoke_impl::invoke_impl;
+
+struct Schedulers;
+
+#[invoke_impl]
+impl Schedulers {
+    pub fn fifo(jobs: u32) -> u32 {
+        jobs
+    }
+
+    #[invoke_serde_name("round_robin")]
+    pub fn rr(jobs: u32) -> u32 {
+        jobs / 2
+    }
+}
+
+#[test]
+fn variants_deserialize_from_their_names() {
+    let variant: SchedulersInvokeImpl = serde_json::from_str("\"fifo\"").unwrap();
+    assert_eq!(variant, SchedulersInvokeImpl::fifo);
+
+    let config: Vec<SchedulersInvokeImpl> =
+        serde_json::from_str("[\"round_robin\", \"fifo\"]").unwrap();
+    let mut results = vec![];
+    Schedulers::invoke_enum(8, |e, r| results.push((e, r)), config.into_iter());
+    assert_eq!(
+        results,
+        vec![
+            (SchedulersInvokeImpl::rr, 4),
+            (SchedulersInvokeImpl::fifo, 8)
+        ]
+    );
+}
+
+#[test]
+fn unknown_names_are_rejected() {
+    let error = serde_json::from_str::<SchedulersInvokeImpl>("\"lifo\"").unwrap_err();
+    assert!(error.to_string().contains("unknown variant `lifo`"));
+    assert!(serde_json::from_str::<SchedulersInvokeImpl>("\"rr\"").is_err());
+}
+
+#[test]
+fn variants_serialize_as_their_names() {
+    let names = serde_json::to_string(&SchedulersInvokeImpl::VARIANTS).unwrap();
+    assert_eq!(names, "[\"fifo\",\"round_robin\"]");
+}
@@ -0,0 +1,38 @@
+use invoke_impl::invoke_impl;
+
+struct Scorers;
+
+#[invoke_impl(skip("normalize", "clamp"))]
+impl Scorers {
+    pub fn length(s: &str) -> usize {
+        Scorers::clamp(Scorers::normalize(s).len())
+    }
+
+    fn normalize(s: &str) -> String {
+        s.trim().to_lowercase()
+    }
+
+    pub fn vowels(s: &str) -> usize {
+        Scorers::clamp(
+            Scorers::normalize(s)
+                .chars()
+                .filter(|c| "aeiou".contains(*c))
+                .count(),
+        )
+    }
+
+    fn clamp(score: usize) -> usize {
+        score.min(5)
+    }
+}
+
+#[test]
+fn skipped_functions_are_left_out() {
+    assert_eq!(Scorers::METHOD_COUNT, 2);
+    assert_eq!(Scorers::METHOD_LIST, ["length", "vowels"]);
+    assert_eq!(ScorersInvokeImpl::iter().count(), 2);
+
+    let mut scores = vec![];
+    Scorers::invoke_all(" Banana ", |score| scores.push(score));
+    assert_eq!(scores, vec![5, 3]);
+}
@@ -0,0 +1,144 @@
+use invoke_impl::invoke_impl;
+use std::cell::Cell;
+use std::rc::Rc;
+
+struct Expensive;
+
+#[invoke_impl(clone(0))]
+impl Expensive {
+    pub fn len(s: String, n: usize) -> usize {
+        s.len() + n
+    }
+
+    pub fn upper(s: String, n: usize) -> usize {
+        s.to_uppercase().len() * n
+    }
+
+    pub fn words(s: String, n: usize) -> usize {
+        s.split_whitespace().count() + n
+    }
+}
+
+#[test]
+fn invoke_subset_lazy_only_builds_selected_arguments() {
+    let mut built = vec![];
+    let mut results = vec![];
+    Expensive::invoke_subset_lazy(
+        |i| {
+            built.push(i);
+            (String::from("lazy args"), i)
+        },
+        |r| results.push(r),
+        [2, 0].into_iter(),
+    );
+    assert_eq!(built, vec![2, 0]);
+    assert_eq!(results, vec![4, 9]);
+}
+
+/// Counts how often it is cloned
+struct Payload(Rc<Cell<usize>>);
+
+impl Clone for Payload {
+    fn clone(&self) -> Self {
+        self.0.set(self.0.get() + 1);
+        Payload(Rc::clone(&self.0))
+    }
+}
+
+struct Consumers;
+
+#[invoke_impl(clone(0))]
+impl Consumers {
+    pub fn first(p: Payload) -> usize {
+        Rc::strong_count(&p.0)
+    }
+
+    pub fn second(p: Payload) -> usize {
+        Rc::strong_count(&p.0)
+    }
+
+    pub fn third(p: Payload) -> usize {
+        Rc::strong_count(&p.0)
+    }
+}
+
+#[test]
+fn invoke_all_moves_cloned_parameters_into_last_call() {
+    let clones = Rc::new(Cell::new(0));
+    let mut results = vec![];
+    Consumers::invoke_all(Payload(Rc::clone(&clones)), |r| results.push(r));
+    assert_eq!(clones.get(), 2);
+    // The last call owns the original, so only it and the test hold the counter
+    assert_eq!(results, vec![3, 3, 2]);
+
+    // invoke_subset clones for every call, as it can't know which one is last
+    let clones = Rc::new(Cell::new(0));
+    Consumers::invoke_subset(Payload(Rc::clone(&clones)), |_| (), [2, 0].into_iter());
+    assert_eq!(clones.get(), 2);
+}
+
+struct Holder;
+
+// Clone indices don't count self, so 0 is the first parameter here too
+#[invoke_impl(clone(0))]
+impl Holder {
+    pub fn first(&self, p: Payload) -> usize {
+        Rc::strong_count(&p.0)
+    }
+
+    pub fn second(&self, p: Payload) -> usize {
+        Rc::strong_count(&p.0)
+    }
+
+    pub fn third(&self, p: Payload) -> usize {
+        Rc::strong_count(&p.0)
+    }
+}
+
+#[test]
+fn clone_indices_skip_the_receiver() {
+    // The associated functions and the methods clone their first parameter alike
+    let clones = Rc::new(Cell::new(0));
+    Consumers::invoke_subset(Payload(Rc::clone(&clones)), |_| (), [0, 1, 2].into_iter());
+    assert_eq!(clones.get(), 3);
+
+    let clones = Rc::new(Cell::new(0));
+    Holder.invoke_subset(Payload(Rc::clone(&clones)), |_| (), [0, 1, 2].into_iter());
+    assert_eq!(clones.get(), 3);
+}
+
+#[cfg(feature = "std")]
+/// Counts how often each of its methods is called
+struct Counters {
+    calls: Cell<[usize; 2]>,
+}
+
+#[cfg(feature = "std")]
+#[invoke_impl]
+impl Counters {
+    pub fn left(&self) -> usize {
+        let mut calls = self.calls.get();
+        calls[0] += 1;
+        self.calls.set(calls);
+        0
+    }
+
+    pub fn right(&self) -> usize {
+        let mut calls = self.calls.get();
+        calls[1] += 1;
+        self.calls.set(calls);
+        1
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn invoke_subset_unique_skips_repeated_indices() {
+    let counters = Counters {
+        calls: Cell::new([0; 2]),
+    };
+    let mut results = vec![];
+    counters.invoke_subset_unique(|r| results.push(r), [1, 0, 1, 1, 0].into_iter());
+    assert_eq!(results, vec![1, 0]);
+    assert_eq!(counters.calls.get(), [1, 1]);
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "timing")]
+
+use invoke_impl::invoke_impl;
+use std::time::Duration;
+
+struct Sleepers;
+
+#[invoke_impl]
+impl Sleepers {
+    pub fn slow(ms: u64) -> u64 {
+        std::thread::sleep(Duration::from_millis(ms * 4));
+        ms * 4
+    }
+
+    pub fn fast(ms: u64) -> u64 {
+        ms
+    }
+
+    pub fn medium(ms: u64) -> u64 {
+        std::thread::sleep(Duration::from_millis(ms * 2));
+        ms * 2
+    }
+}
+
+#[test]
+fn invoke_all_fastest_returns_fastest_method() {
+    let (fastest, elapsed, result) = Sleepers::invoke_all_fastest(10);
+    assert!(matches!(fastest, SleepersInvokeImpl::fast));
+    assert!(elapsed < Duration::from_millis(20));
+    assert_eq!(result, 10);
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "std")]
+
+use invoke_impl::invoke_impl;
+
+trait Shape {
+    fn area(&self) -> f64;
+    fn perimeter(&self) -> f64;
+}
+
+struct Square(f64);
+
+#[invoke_impl]
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.0 * 4.0
+    }
+}
+
+#[test]
+fn trait_impl_gets_inherent_invoke_functions() {
+    let square = Square(2.0);
+    let mut results = vec![];
+    square.invoke_all(|r| results.push(r));
+    assert_eq!(results, vec![4.0, 8.0]);
+    assert_eq!(square.invoke_all_collect(), vec![4.0, 8.0]);
+    assert_eq!(Square::METHOD_LIST, ["area", "perimeter"]);
+    assert_eq!(square.run_perimeter(), 8.0);
+}
+
+trait Named {
+    fn short() -> String;
+    fn long() -> String;
+}
+
+struct Wrapper<T>(T);
+
+#[invoke_impl]
+impl<T: Default + std::fmt::Debug> Named for Wrapper<T> {
+    fn short() -> String {
+        format!("{:?}", T::default())
+    }
+
+    fn long() -> String {
+        format!("Wrapper({:?})", T::default())
+    }
+}
+
+#[test]
+fn generic_trait_impl_keeps_its_generics() {
+    assert_eq!(
+        Wrapper::<u8>::invoke_all_collect(),
+        vec![String::from("0"), String::from("Wrapper(0)")]
+    );
+    assert_eq!(WrapperInvokeImpl::long.index(), 1);
+}
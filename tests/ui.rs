@@ -0,0 +1,13 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    t.pass("tests/pass/*.rs");
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn ui_rayon() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/rayon/*.rs");
+}
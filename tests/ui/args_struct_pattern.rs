@@ -0,0 +1,22 @@
+use invoke_impl::invoke_impl;
+
+struct Points;
+
+struct PointArgs {
+    point: (i32, i32),
+}
+
+#[invoke_impl(args_struct(PointArgs))]
+impl Points {
+    pub fn sum((x, y): (i32, i32)) -> i32 {
+        x + y
+    }
+
+    pub fn product((x, y): (i32, i32)) -> i32 {
+        x * y
+    }
+}
+
+fn main() {
+    let _ = PointArgs { point: (1, 2) }.point;
+}
@@ -0,0 +1,16 @@
+use invoke_impl::invoke_impl;
+
+struct Mismatched;
+
+#[invoke_impl]
+impl Mismatched {
+    pub async fn fn1(i: i32) -> i32 {
+        i
+    }
+
+    pub fn fn2(i: i32) -> i32 {
+        i
+    }
+}
+
+fn main() {}
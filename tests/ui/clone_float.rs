@@ -0,0 +1,12 @@
+use invoke_impl::invoke_impl;
+
+struct Floats;
+
+#[invoke_impl(clone(0, 1.5))]
+impl Floats {
+    pub fn fn1(s: String) -> usize {
+        s.len()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use invoke_impl::invoke_impl;
+
+struct Strings;
+
+#[invoke_impl(clone(1, 2))]
+impl Strings {
+    pub fn fn1(i: usize, s: String) -> usize {
+        s.len() + i
+    }
+
+    pub fn fn2(i: usize, s: String) -> usize {
+        s.len() * i
+    }
+}
+
+fn main() {}
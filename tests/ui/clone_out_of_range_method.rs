@@ -0,0 +1,17 @@
+use invoke_impl::invoke_impl;
+
+struct Strings;
+
+// self isn't counted, so the only parameter is 0
+#[invoke_impl(clone(1))]
+impl Strings {
+    pub fn fn1(&self, s: String) -> usize {
+        s.len()
+    }
+
+    pub fn fn2(&self, s: String) -> usize {
+        s.len() * 2
+    }
+}
+
+fn main() {}
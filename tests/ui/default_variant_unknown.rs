@@ -0,0 +1,16 @@
+use invoke_impl::invoke_impl;
+
+struct Modes;
+
+#[invoke_impl(default_variant(balance))]
+impl Modes {
+    pub fn fast(x: u32) -> u32 {
+        x
+    }
+
+    pub fn balanced(x: u32) -> u32 {
+        x * 2
+    }
+}
+
+fn main() {}
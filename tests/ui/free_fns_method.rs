@@ -0,0 +1,18 @@
+use invoke_impl::invoke_impl;
+
+struct Counter {
+    count: u32,
+}
+
+#[invoke_impl(free_fns)]
+impl Counter {
+    pub fn double(&self) -> u32 {
+        self.count * 2
+    }
+
+    pub fn triple(&self) -> u32 {
+        self.count * 3
+    }
+}
+
+fn main() {}
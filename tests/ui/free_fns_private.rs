@@ -0,0 +1,16 @@
+use invoke_impl::invoke_impl;
+
+struct Checksums;
+
+#[invoke_impl(free_fns)]
+impl Checksums {
+    fn sum(data: &[u8]) -> u32 {
+        data.iter().map(|&b| b as u32).sum()
+    }
+
+    fn xor(data: &[u8]) -> u32 {
+        data.iter().fold(0, |acc, &b| acc ^ b as u32)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use invoke_impl::invoke_impl;
+
+struct Filters;
+
+#[invoke_impl]
+impl Filters {
+    pub fn blur(x: f32) -> f32 {
+        x * 0.5
+    }
+
+    pub fn sharpen(x: f32) -> f32 {
+        x * 2.0
+    }
+
+    pub fn invoke_all(x: f32) -> Vec<f32> {
+        vec![Self::blur(x), Self::sharpen(x)]
+    }
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use invoke_impl::invoke_impl;
+
+struct ScaleArgs {
+    x: f32,
+}
+
+struct Filters;
+
+#[invoke_impl(args_struct(ScaleArgs))]
+impl Filters {
+    pub fn blur(x: f32) -> f32 {
+        x * 0.5
+    }
+
+    pub fn sharpen(x: f32) -> f32 {
+        x * 2.0
+    }
+
+    pub fn invoke_all_from(x: f32) -> f32 {
+        Self::blur(Self::sharpen(x))
+    }
+}
+
+fn main() {}
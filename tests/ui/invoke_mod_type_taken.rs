@@ -0,0 +1,12 @@
+use invoke_impl::invoke_mod;
+
+#[invoke_mod]
+mod shapes {
+    pub struct Shapes;
+
+    pub fn square(x: f64) -> f64 {
+        x * x
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use invoke_impl::invoke_impl;
+
+struct Counter {
+    count: u32,
+}
+
+#[invoke_impl(invoker("CounterInvoker"))]
+impl Counter {
+    pub fn double(&mut self) -> u32 {
+        self.count *= 2;
+        self.count
+    }
+
+    pub fn triple(&mut self) -> u32 {
+        self.count *= 3;
+        self.count
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use invoke_impl::invoke_impl;
+
+struct Audio;
+
+#[invoke_impl(prefix("audio"); skip("audio_invoke_all"))]
+impl Audio {
+    pub fn left(x: i32) -> i32 {
+        x
+    }
+
+    pub fn right(x: i32) -> i32 {
+        -x
+    }
+
+    pub fn audio_invoke_all() {}
+}
+
+fn main() {}
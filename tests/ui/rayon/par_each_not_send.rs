@@ -0,0 +1,21 @@
+use invoke_impl::invoke_impl;
+use std::rc::Rc;
+
+struct Crunchers;
+
+#[invoke_impl(clone(0); parallel)]
+impl Crunchers {
+    pub fn double(n: u64) -> u64 {
+        n * 2
+    }
+
+    pub fn square(n: u64) -> u64 {
+        n * n
+    }
+}
+
+fn main() {
+    let total = Rc::new(std::cell::Cell::new(0));
+    let counter = Rc::clone(&total);
+    Crunchers::invoke_all_par_each(3, move |r| counter.set(counter.get() + r));
+}
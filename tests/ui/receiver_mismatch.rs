@@ -0,0 +1,16 @@
+use invoke_impl::invoke_impl;
+
+struct Mixed;
+
+#[invoke_impl]
+impl Mixed {
+    pub fn fn1(i: i32) -> i32 {
+        i
+    }
+
+    pub fn fn2(&self, i: i32) -> i32 {
+        i + 1
+    }
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+#![deny(deprecated)]
+
+use invoke_impl::invoke_impl;
+
+struct FanOut;
+
+#[invoke_impl]
+impl FanOut {
+    pub fn fn1(i: u32) -> u32 {
+        if i > 0 {
+            let mut total = 0;
+            Self::invoke_all(i - 1, |r| total += r);
+            total
+        } else {
+            1
+        }
+    }
+
+    // Guarded recursion the warning can be silenced for
+    #[allow(deprecated)]
+    pub fn fn2(i: u32) -> u32 {
+        if i > 0 {
+            Self::invoke_all_fold(i - 1, 0, |acc, r| acc + r)
+        } else {
+            2
+        }
+    }
+}
+
+fn main() {}
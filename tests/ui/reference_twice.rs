@@ -0,0 +1,18 @@
+use invoke_impl::invoke_impl;
+
+struct Solvers;
+
+#[invoke_impl]
+impl Solvers {
+    #[invoke_reference]
+    pub fn fast(x: f64) -> f64 {
+        x * 0.5
+    }
+
+    #[invoke_reference]
+    pub fn exact(x: f64) -> f64 {
+        x / 2.0
+    }
+}
+
+fn main() {}
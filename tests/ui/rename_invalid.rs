@@ -0,0 +1,16 @@
+use invoke_impl::invoke_impl;
+
+struct Renamed;
+
+#[invoke_impl(rename(fn1 = "First Function"))]
+impl Renamed {
+    pub fn fn1(i: i32) -> i32 {
+        i
+    }
+
+    pub fn fn2(i: i32) -> i32 {
+        i * 2
+    }
+}
+
+fn main() {}
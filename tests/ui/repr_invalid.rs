@@ -0,0 +1,10 @@
+use invoke_impl::invoke_impl;
+
+struct Packed;
+
+#[invoke_impl(repr(i8))]
+impl Packed {
+    pub fn fn1() {}
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use invoke_impl::invoke_impl;
+
+struct Mismatched;
+
+#[invoke_impl]
+impl Mismatched {
+    pub fn foo(i: u8) -> Vec<(u8, &'static str)> {
+        vec![(i, "foo")]
+    }
+
+    pub fn bar(i: u8) -> Vec<(u32, &'static str)> {
+        vec![(i as u32, "bar")]
+    }
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use invoke_impl::invoke_impl;
+
+#[derive(Clone)]
+struct Builder;
+
+#[invoke_impl]
+impl Builder {
+    pub fn build(self) -> u8 {
+        0
+    }
+}
+
+fn main() {}
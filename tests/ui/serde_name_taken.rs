@@ -0,0 +1,17 @@
+use invoke_impl::invoke_impl;
+
+struct Ciphers;
+
+#[invoke_impl]
+impl Ciphers {
+    pub fn caesar(shift: u8) -> u8 {
+        shift % 26
+    }
+
+    #[invoke_serde_name("caesar")]
+    pub fn vigenere(shift: u8) -> u8 {
+        shift.wrapping_mul(3)
+    }
+}
+
+fn main() {}
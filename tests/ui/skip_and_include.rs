@@ -0,0 +1,16 @@
+use invoke_impl::invoke_impl;
+
+struct Passes;
+
+#[invoke_impl(include("forward"); skip("scale"))]
+impl Passes {
+    pub fn forward(x: f64) -> f64 {
+        Passes::scale() * x
+    }
+
+    fn scale() -> f64 {
+        2.0
+    }
+}
+
+fn main() {}
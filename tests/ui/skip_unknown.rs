@@ -0,0 +1,16 @@
+use invoke_impl::invoke_impl;
+
+struct Scorers;
+
+#[invoke_impl(skip("normalise"))]
+impl Scorers {
+    pub fn length(s: &str) -> usize {
+        Scorers::normalize(s).len()
+    }
+
+    fn normalize(s: &str) -> String {
+        s.trim().to_lowercase()
+    }
+}
+
+fn main() {}
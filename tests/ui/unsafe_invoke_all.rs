@@ -0,0 +1,19 @@
+use invoke_impl::invoke_impl;
+
+struct RawReaders;
+
+#[invoke_impl]
+impl RawReaders {
+    pub unsafe fn read(ptr: *const u32) -> u32 {
+        *ptr
+    }
+
+    pub unsafe fn read_doubled(ptr: *const u32) -> u32 {
+        *ptr * 2
+    }
+}
+
+fn main() {
+    let value = 21;
+    RawReaders::invoke_all(&value, |_| ());
+}
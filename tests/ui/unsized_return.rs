@@ -0,0 +1,16 @@
+use invoke_impl::invoke_impl;
+
+struct Labels;
+
+#[invoke_impl]
+impl Labels {
+    pub fn short(_i: usize) -> str {
+        todo!()
+    }
+
+    pub fn long(_i: usize) -> str {
+        todo!()
+    }
+}
+
+fn main() {}
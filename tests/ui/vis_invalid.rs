@@ -0,0 +1,12 @@
+use invoke_impl::invoke_impl;
+
+struct Kinds;
+
+#[invoke_impl(vis("crate"))]
+impl Kinds {
+    pub fn fn1(i: i32) -> i32 {
+        i
+    }
+}
+
+fn main() {}
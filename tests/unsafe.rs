@@ -0,0 +1,46 @@
+#![cfg(feature = "std")]
+// The generated functions call the unsafe functions in unsafe blocks, as edition 2024 requires
+#![deny(unsafe_op_in_unsafe_fn)]
+
+use invoke_impl::invoke_impl;
+
+struct RawReaders;
+
+#[invoke_impl(free_fns)]
+impl RawReaders {
+    /// # Safety
+    ///
+    /// ptr must be valid for reads
+    pub unsafe fn read(ptr: *const u32) -> u32 {
+        unsafe { *ptr }
+    }
+
+    /// # Safety
+    ///
+    /// ptr must be valid for reads
+    pub unsafe fn read_doubled(ptr: *const u32) -> u32 {
+        unsafe { *ptr * 2 }
+    }
+}
+
+#[test]
+fn unsafe_invoke_all() {
+    let value = 21;
+    let mut results = vec![];
+    unsafe { RawReaders::invoke_all(&value, |r| results.push(r)) };
+    assert_eq!(results, vec![21, 42]);
+
+    let mut results = vec![];
+    unsafe { RawReaders::invoke_subset(&value, |r| results.push(r), [1].into_iter()) };
+    assert_eq!(results, vec![42]);
+
+    assert_eq!(
+        unsafe { RawReaders::invoke_all_collect(&value) },
+        vec![21, 42]
+    );
+    assert_eq!(unsafe { RawReaders::run_read_doubled(&value) }, 42);
+    assert_eq!(
+        unsafe { raw_readers_invoke::all_collect(&value) },
+        vec![21, 42]
+    );
+}
@@ -0,0 +1,64 @@
+use invoke_impl::invoke_impl;
+use std::cell::Cell;
+use std::ops::ControlFlow;
+
+struct Stages {
+    calls: Cell<usize>,
+}
+
+#[invoke_impl]
+impl Stages {
+    pub fn parse(&self, input: u32) -> u32 {
+        self.calls.set(self.calls.get() + 1);
+        input + 1
+    }
+
+    pub fn check(&self, input: u32) -> u32 {
+        self.calls.set(self.calls.get() + 1);
+        input * 2
+    }
+
+    pub fn emit(&self, input: u32) -> u32 {
+        self.calls.set(self.calls.get() + 1);
+        input * 3
+    }
+}
+
+#[test]
+fn invoke_all_until_stops_after_break() {
+    let stages = Stages {
+        calls: Cell::new(0),
+    };
+    let mut seen = vec![];
+    stages.invoke_all_until(5, |r| {
+        seen.push(r);
+        if seen.len() == 2 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+    assert_eq!(seen, vec![6, 10]);
+    assert_eq!(stages.calls.get(), 2);
+
+    stages.invoke_all_until(5, |_| ControlFlow::Continue(()));
+    assert_eq!(stages.calls.get(), 5);
+}
+
+#[test]
+fn invoke_all_enumerated_until_stops_at_index() {
+    let stages = Stages {
+        calls: Cell::new(0),
+    };
+    let mut seen = vec![];
+    stages.invoke_all_enumerated_until(5, |i, r| {
+        seen.push((i, r));
+        if i == 1 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+    assert_eq!(seen, vec![(0, 6), (1, 10)]);
+    assert_eq!(stages.calls.get(), 2);
+}
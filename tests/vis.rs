@@ -0,0 +1,32 @@
+#![cfg(feature = "std")]
+
+mod engines {
+    use invoke_impl::invoke_impl;
+
+    pub struct Engines;
+
+    #[invoke_impl(vis("pub(crate)"))]
+    impl Engines {
+        fn diesel(load: u32) -> u32 {
+            load * 3
+        }
+
+        fn electric(load: u32) -> u32 {
+            load * 2
+        }
+    }
+}
+
+use engines::{Engines, EnginesInvokeImpl};
+
+#[test]
+fn vis_applies_to_generated_items() {
+    assert_eq!(Engines::invoke_all_collect(5), vec![15, 10]);
+    assert_eq!(Engines::run_electric(5), 10);
+    assert_eq!(Engines::METHOD_COUNT, 2);
+    assert_eq!(Engines::METHOD_LIST, ["diesel", "electric"]);
+    assert_eq!(
+        EnginesInvokeImpl::from_index(1),
+        Some(EnginesInvokeImpl::electric)
+    );
+}